@@ -1,5 +1,7 @@
 // Part of a very simple ECS for demonstration purposes only.
 
+use std::ops::Range;
+
 use morphorm::*;
 use slotmap::SecondaryMap;
 
@@ -23,8 +25,8 @@ impl Node for Entity {
         ChildIterator { tree, current_node }
     }
 
-    fn visible(&self, store: &Store) -> bool {
-        store.visible.get(*self).copied().unwrap_or(true)
+    fn visibility(&self, store: &Store) -> Option<Visibility> {
+        store.visibility.get(*self).copied()
     }
 
     fn layout_type(&self, store: &Store) -> Option<LayoutType> {
@@ -35,6 +37,34 @@ impl Node for Entity {
         store.position_type.get(*self).copied()
     }
 
+    fn contributes_to_size(&self, store: &Store) -> Option<bool> {
+        store.contributes_to_size.get(*self).copied()
+    }
+
+    fn order(&self, store: &Store) -> Option<i32> {
+        store.order.get(*self).copied()
+    }
+
+    fn overflow(&self, store: &Store) -> Option<Overflow> {
+        store.overflow.get(*self).copied()
+    }
+
+    fn overflow_priority(&self, store: &Store) -> Option<i32> {
+        store.overflow_priority.get(*self).copied()
+    }
+
+    fn scroll_x(&self, store: &Store) -> Option<f32> {
+        store.scroll_x.get(*self).copied()
+    }
+
+    fn scroll_y(&self, store: &Store) -> Option<f32> {
+        store.scroll_y.get(*self).copied()
+    }
+
+    fn virtual_list(&self, store: &Store) -> Option<VirtualList> {
+        store.virtual_list.get(*self).copied()
+    }
+
     fn width(&self, store: &Store) -> Option<Units> {
         store.width.get(*self).copied()
     }
@@ -59,6 +89,14 @@ impl Node for Entity {
         store.bottom.get(*self).copied()
     }
 
+    fn translate_x(&self, store: &Store) -> Option<Units> {
+        store.translate_x.get(*self).copied()
+    }
+
+    fn translate_y(&self, store: &Store) -> Option<Units> {
+        store.translate_y.get(*self).copied()
+    }
+
     fn content_size<'a>(
         &self,
         store: &Store,
@@ -69,6 +107,22 @@ impl Node for Entity {
         store.content_size.get(*self).map(|t| (t)(store, width, height))
     }
 
+    fn content_size_mode(&self, store: &Store) -> Option<ContentSizeMode> {
+        store.content_size_mode.get(*self).copied()
+    }
+
+    fn uniform_children(&self, store: &Store) -> Option<UniformChildren> {
+        store.uniform_children.get(*self).copied()
+    }
+
+    fn size_group(&self, store: &Store) -> Option<u32> {
+        store.size_group.get(*self).copied()
+    }
+
+    fn breakpoint(&self, store: &Store, query: &LayoutQuery) -> Option<Breakpoint> {
+        store.breakpoint.get(*self).and_then(|t| (t)(query))
+    }
+
     fn child_left(&self, store: &Store) -> Option<Units> {
         store.child_left.get(*self).copied()
     }
@@ -93,6 +147,90 @@ impl Node for Entity {
         store.col_between.get(*self).copied()
     }
 
+    fn column_count(&self, store: &Store) -> Option<usize> {
+        store.column_count.get(*self).copied()
+    }
+
+    fn min_column_width(&self, store: &Store) -> Option<Units> {
+        store.min_column_width.get(*self).copied()
+    }
+
+    fn start_angle(&self, store: &Store) -> Option<f32> {
+        store.start_angle.get(*self).copied()
+    }
+
+    fn angular_span(&self, store: &Store) -> Option<f32> {
+        store.angular_span.get(*self).copied()
+    }
+
+    fn radius(&self, store: &Store) -> Option<Units> {
+        store.radius.get(*self).copied()
+    }
+
+    fn dock(&self, store: &Store) -> Option<Dock> {
+        store.dock.get(*self).copied()
+    }
+
+    fn min_child_left(&self, store: &Store) -> Option<Units> {
+        store.min_child_left.get(*self).copied()
+    }
+
+    fn min_child_right(&self, store: &Store) -> Option<Units> {
+        store.min_child_right.get(*self).copied()
+    }
+
+    fn min_child_top(&self, store: &Store) -> Option<Units> {
+        store.min_child_top.get(*self).copied()
+    }
+
+    fn min_child_bottom(&self, store: &Store) -> Option<Units> {
+        store.min_child_bottom.get(*self).copied()
+    }
+
+    fn max_child_left(&self, store: &Store) -> Option<Units> {
+        store.max_child_left.get(*self).copied()
+    }
+
+    fn max_child_right(&self, store: &Store) -> Option<Units> {
+        store.max_child_right.get(*self).copied()
+    }
+
+    fn max_child_top(&self, store: &Store) -> Option<Units> {
+        store.max_child_top.get(*self).copied()
+    }
+
+    fn max_child_bottom(&self, store: &Store) -> Option<Units> {
+        store.max_child_bottom.get(*self).copied()
+    }
+
+    fn min_row_between(&self, store: &Store) -> Option<Units> {
+        store.min_row_between.get(*self).copied()
+    }
+
+    fn max_row_between(&self, store: &Store) -> Option<Units> {
+        store.max_row_between.get(*self).copied()
+    }
+
+    fn min_col_between(&self, store: &Store) -> Option<Units> {
+        store.min_col_between.get(*self).copied()
+    }
+
+    fn max_col_between(&self, store: &Store) -> Option<Units> {
+        store.max_col_between.get(*self).copied()
+    }
+
+    fn main_alignment(&self, store: &Store) -> Option<MainAlignment> {
+        store.main_alignment.get(*self).copied()
+    }
+
+    fn cross_alignment(&self, store: &Store) -> Option<CrossAlignment> {
+        store.cross_alignment.get(*self).copied()
+    }
+
+    fn align_self(&self, store: &Store) -> Option<CrossAlignment> {
+        store.align_self.get(*self).copied()
+    }
+
     fn min_width(&self, store: &Store) -> Option<Units> {
         store.min_width.get(*self).copied()
     }
@@ -156,6 +294,30 @@ impl Node for Entity {
     fn border_bottom(&self, store: &Store) -> Option<Units> {
         store.border_bottom.get(*self).copied()
     }
+
+    fn box_sizing(&self, store: &Store) -> Option<BoxSizing> {
+        store.box_sizing.get(*self).copied()
+    }
+
+    fn padding_left(&self, store: &Store) -> Option<Units> {
+        store.padding_left.get(*self).copied()
+    }
+
+    fn padding_right(&self, store: &Store) -> Option<Units> {
+        store.padding_right.get(*self).copied()
+    }
+
+    fn padding_top(&self, store: &Store) -> Option<Units> {
+        store.padding_top.get(*self).copied()
+    }
+
+    fn padding_bottom(&self, store: &Store) -> Option<Units> {
+        store.padding_bottom.get(*self).copied()
+    }
+
+    fn font_size(&self, store: &Store) -> Option<f32> {
+        store.font_size.get(*self).copied()
+    }
 }
 
 #[derive(Debug, Default, Clone, Copy, PartialEq)]
@@ -170,24 +332,51 @@ pub struct Rect {
 pub struct NodeCache {
     // Computed size and position of nodes.
     pub rect: SecondaryMap<Entity, Rect>,
+    // Whether nodes have been collapsed because they do not fit within their parent.
+    pub overflowed: SecondaryMap<Entity, bool>,
+    // Computed content extent of nodes.
+    pub content_extent: SecondaryMap<Entity, (f32, f32)>,
+    // Range of items laid out for virtual lists.
+    pub visible_range: SecondaryMap<Entity, Range<usize>>,
 }
 
 impl NodeCache {
     pub fn add(&mut self, entity: Entity) {
         self.rect.insert(entity, Default::default());
+        self.overflowed.insert(entity, false);
+        self.content_extent.insert(entity, Default::default());
+        self.visible_range.insert(entity, Default::default());
     }
 
     pub fn remove(&mut self, entity: Entity) {
         self.rect.remove(entity);
+        self.overflowed.remove(entity);
+        self.content_extent.remove(entity);
+        self.visible_range.remove(entity);
     }
 
     pub fn clear(&mut self) {
         self.rect.clear();
+        self.overflowed.clear();
+        self.content_extent.clear();
+        self.visible_range.clear();
     }
 
     pub fn bounds(&self, entity: Entity) -> Option<&Rect> {
         self.rect.get(entity)
     }
+
+    pub fn overflowed(&self, entity: Entity) -> bool {
+        self.overflowed.get(entity).copied().unwrap_or_default()
+    }
+
+    pub fn content_extent(&self, entity: Entity) -> Option<(f32, f32)> {
+        self.content_extent.get(entity).copied()
+    }
+
+    pub fn visible_range(&self, entity: Entity) -> Option<Range<usize>> {
+        self.visible_range.get(entity).cloned()
+    }
 }
 
 impl Cache for NodeCache {
@@ -202,6 +391,28 @@ impl Cache for NodeCache {
         }
     }
 
+    fn set_overflowed(&mut self, node: &Self::Node, overflowed: bool) {
+        if let Some(value) = self.overflowed.get_mut(*node) {
+            *value = overflowed;
+        }
+    }
+
+    fn set_content_extent(&mut self, node: &Self::Node, width: f32, height: f32) {
+        if let Some(extent) = self.content_extent.get_mut(*node) {
+            *extent = (width, height);
+        }
+    }
+
+    fn visible_range(&self, node: &Self::Node) -> Option<Range<usize>> {
+        self.visible_range.get(*node).cloned()
+    }
+
+    fn set_visible_range(&mut self, node: &Self::Node, range: Range<usize>) {
+        if let Some(value) = self.visible_range.get_mut(*node) {
+            *value = range;
+        }
+    }
+
     fn width(&self, node: &Self::Node) -> f32 {
         if let Some(rect) = self.rect.get(*node) {
             return rect.width;
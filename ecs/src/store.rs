@@ -1,24 +1,38 @@
 // Part of a very simple ECS for demonstration purposes only.
 
 use crate::{entity::Entity, TextWrap};
-use morphorm::{LayoutType, PositionType, Units};
+use morphorm::{
+    BoxSizing, Breakpoint, ContentSizeMode, CrossAlignment, Dock, LayoutQuery, LayoutType, MainAlignment, Overflow,
+    PositionType, UniformChildren, Units, VirtualList, Visibility,
+};
 use slotmap::SecondaryMap;
 
 type ContentSizeType<S> = Box<dyn Fn(&S, Option<f32>, Option<f32>) -> (f32, f32)>;
+type BreakpointType = Box<dyn Fn(&LayoutQuery) -> Option<Breakpoint>>;
 
 /// A storage struct representing a component store for an ECS.
 #[derive(Default)]
 pub struct Store {
-    pub visible: SecondaryMap<Entity, bool>,
+    pub visibility: SecondaryMap<Entity, Visibility>,
 
     pub layout_type: SecondaryMap<Entity, LayoutType>,
     pub position_type: SecondaryMap<Entity, PositionType>,
+    pub contributes_to_size: SecondaryMap<Entity, bool>,
+    pub order: SecondaryMap<Entity, i32>,
+    pub overflow: SecondaryMap<Entity, Overflow>,
+    pub overflow_priority: SecondaryMap<Entity, i32>,
+    pub scroll_x: SecondaryMap<Entity, f32>,
+    pub scroll_y: SecondaryMap<Entity, f32>,
+    pub virtual_list: SecondaryMap<Entity, VirtualList>,
 
     pub left: SecondaryMap<Entity, Units>,
     pub right: SecondaryMap<Entity, Units>,
     pub top: SecondaryMap<Entity, Units>,
     pub bottom: SecondaryMap<Entity, Units>,
 
+    pub translate_x: SecondaryMap<Entity, Units>,
+    pub translate_y: SecondaryMap<Entity, Units>,
+
     pub min_left: SecondaryMap<Entity, Units>,
     pub max_left: SecondaryMap<Entity, Units>,
     pub min_right: SecondaryMap<Entity, Units>,
@@ -40,9 +54,34 @@ pub struct Store {
     pub child_top: SecondaryMap<Entity, Units>,
     pub child_bottom: SecondaryMap<Entity, Units>,
     pub col_between: SecondaryMap<Entity, Units>,
+    pub column_count: SecondaryMap<Entity, usize>,
+    pub min_column_width: SecondaryMap<Entity, Units>,
+    pub start_angle: SecondaryMap<Entity, f32>,
+    pub angular_span: SecondaryMap<Entity, f32>,
+    pub radius: SecondaryMap<Entity, Units>,
+    pub dock: SecondaryMap<Entity, Dock>,
     pub row_between: SecondaryMap<Entity, Units>,
+    pub min_child_left: SecondaryMap<Entity, Units>,
+    pub min_child_right: SecondaryMap<Entity, Units>,
+    pub min_child_top: SecondaryMap<Entity, Units>,
+    pub min_child_bottom: SecondaryMap<Entity, Units>,
+    pub max_child_left: SecondaryMap<Entity, Units>,
+    pub max_child_right: SecondaryMap<Entity, Units>,
+    pub max_child_top: SecondaryMap<Entity, Units>,
+    pub max_child_bottom: SecondaryMap<Entity, Units>,
+    pub min_row_between: SecondaryMap<Entity, Units>,
+    pub max_row_between: SecondaryMap<Entity, Units>,
+    pub min_col_between: SecondaryMap<Entity, Units>,
+    pub max_col_between: SecondaryMap<Entity, Units>,
+    pub main_alignment: SecondaryMap<Entity, MainAlignment>,
+    pub cross_alignment: SecondaryMap<Entity, CrossAlignment>,
+    pub align_self: SecondaryMap<Entity, CrossAlignment>,
 
     pub content_size: SecondaryMap<Entity, ContentSizeType<Self>>,
+    pub content_size_mode: SecondaryMap<Entity, ContentSizeMode>,
+    pub uniform_children: SecondaryMap<Entity, UniformChildren>,
+    pub size_group: SecondaryMap<Entity, u32>,
+    pub breakpoint: SecondaryMap<Entity, BreakpointType>,
 
     pub text: SecondaryMap<Entity, String>,
     pub text_wrap: SecondaryMap<Entity, TextWrap>,
@@ -58,17 +97,34 @@ pub struct Store {
     pub border_right: SecondaryMap<Entity, Units>,
     pub border_top: SecondaryMap<Entity, Units>,
     pub border_bottom: SecondaryMap<Entity, Units>,
+
+    pub box_sizing: SecondaryMap<Entity, BoxSizing>,
+    pub padding_left: SecondaryMap<Entity, Units>,
+    pub padding_right: SecondaryMap<Entity, Units>,
+    pub padding_top: SecondaryMap<Entity, Units>,
+    pub padding_bottom: SecondaryMap<Entity, Units>,
+
+    pub font_size: SecondaryMap<Entity, f32>,
 }
 
 impl Store {
     pub fn remove(&mut self, entity: Entity) {
-        self.visible.remove(entity);
+        self.visibility.remove(entity);
         self.layout_type.remove(entity);
         self.position_type.remove(entity);
+        self.contributes_to_size.remove(entity);
+        self.order.remove(entity);
+        self.overflow.remove(entity);
+        self.overflow_priority.remove(entity);
+        self.scroll_x.remove(entity);
+        self.scroll_y.remove(entity);
+        self.virtual_list.remove(entity);
         self.left.remove(entity);
         self.right.remove(entity);
         self.top.remove(entity);
         self.bottom.remove(entity);
+        self.translate_x.remove(entity);
+        self.translate_y.remove(entity);
         self.min_left.remove(entity);
         self.max_left.remove(entity);
         self.min_right.remove(entity);
@@ -88,8 +144,33 @@ impl Store {
         self.child_top.remove(entity);
         self.child_bottom.remove(entity);
         self.col_between.remove(entity);
+        self.column_count.remove(entity);
+        self.min_column_width.remove(entity);
+        self.start_angle.remove(entity);
+        self.angular_span.remove(entity);
+        self.radius.remove(entity);
+        self.dock.remove(entity);
         self.row_between.remove(entity);
+        self.min_child_left.remove(entity);
+        self.min_child_right.remove(entity);
+        self.min_child_top.remove(entity);
+        self.min_child_bottom.remove(entity);
+        self.max_child_left.remove(entity);
+        self.max_child_right.remove(entity);
+        self.max_child_top.remove(entity);
+        self.max_child_bottom.remove(entity);
+        self.min_row_between.remove(entity);
+        self.max_row_between.remove(entity);
+        self.min_col_between.remove(entity);
+        self.max_col_between.remove(entity);
+        self.main_alignment.remove(entity);
+        self.cross_alignment.remove(entity);
+        self.align_self.remove(entity);
         self.content_size.remove(entity);
+        self.content_size_mode.remove(entity);
+        self.uniform_children.remove(entity);
+        self.size_group.remove(entity);
+        self.breakpoint.remove(entity);
         self.text.remove(entity);
         self.text_wrap.remove(entity);
         self.red.remove(entity);
@@ -99,16 +180,31 @@ impl Store {
         self.border_right.remove(entity);
         self.border_top.remove(entity);
         self.border_bottom.remove(entity);
+        self.box_sizing.remove(entity);
+        self.padding_left.remove(entity);
+        self.padding_right.remove(entity);
+        self.padding_top.remove(entity);
+        self.padding_bottom.remove(entity);
+        self.font_size.remove(entity);
     }
 
     pub fn clear(&mut self) {
-        self.visible.clear();
+        self.visibility.clear();
         self.layout_type.clear();
         self.position_type.clear();
+        self.contributes_to_size.clear();
+        self.order.clear();
+        self.overflow.clear();
+        self.overflow_priority.clear();
+        self.scroll_x.clear();
+        self.scroll_y.clear();
+        self.virtual_list.clear();
         self.left.clear();
         self.right.clear();
         self.top.clear();
         self.bottom.clear();
+        self.translate_x.clear();
+        self.translate_y.clear();
         self.min_left.clear();
         self.max_left.clear();
         self.min_right.clear();
@@ -128,8 +224,33 @@ impl Store {
         self.child_top.clear();
         self.child_bottom.clear();
         self.col_between.clear();
+        self.column_count.clear();
+        self.min_column_width.clear();
+        self.start_angle.clear();
+        self.angular_span.clear();
+        self.radius.clear();
+        self.dock.clear();
         self.row_between.clear();
+        self.min_child_left.clear();
+        self.min_child_right.clear();
+        self.min_child_top.clear();
+        self.min_child_bottom.clear();
+        self.max_child_left.clear();
+        self.max_child_right.clear();
+        self.max_child_top.clear();
+        self.max_child_bottom.clear();
+        self.min_row_between.clear();
+        self.max_row_between.clear();
+        self.min_col_between.clear();
+        self.max_col_between.clear();
+        self.main_alignment.clear();
+        self.cross_alignment.clear();
+        self.align_self.clear();
         self.content_size.clear();
+        self.content_size_mode.clear();
+        self.uniform_children.clear();
+        self.size_group.clear();
+        self.breakpoint.clear();
         self.text.clear();
         self.text_wrap.clear();
         self.red.clear();
@@ -139,5 +260,11 @@ impl Store {
         self.border_right.clear();
         self.border_top.clear();
         self.border_bottom.clear();
+        self.box_sizing.clear();
+        self.padding_left.clear();
+        self.padding_right.clear();
+        self.padding_top.clear();
+        self.padding_bottom.clear();
+        self.font_size.clear();
     }
 }
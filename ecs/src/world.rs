@@ -1,6 +1,9 @@
 // Part of a very simple ECS for demonstration purposes only.
 
-use morphorm::{LayoutType, PositionType, Units};
+use morphorm::{
+    BoxSizing, Breakpoint, ContentSizeMode, CrossAlignment, Dock, LayoutQuery, LayoutType, MainAlignment, Overflow,
+    PositionType, UniformChildren, Units, VirtualList, Visibility,
+};
 
 use crate::entity::{Entity, EntityManager};
 use crate::implementations::NodeCache;
@@ -68,6 +71,41 @@ impl World {
         self.store.position_type.insert(entity, value);
     }
 
+    /// Set whether the given self-directed entity contributes to the size of its parent when the parent size is auto.
+    pub fn set_contributes_to_size(&mut self, entity: Entity, value: bool) {
+        self.store.contributes_to_size.insert(entity, value);
+    }
+
+    /// Set the order of the given parent-directed entity among its siblings.
+    pub fn set_order(&mut self, entity: Entity, value: i32) {
+        self.store.order.insert(entity, value);
+    }
+
+    /// Set how the given entity handles parent-directed children which do not fit within it on the main-axis.
+    pub fn set_overflow(&mut self, entity: Entity, value: Overflow) {
+        self.store.overflow.insert(entity, value);
+    }
+
+    /// Set the overflow priority of the given entity, with lower priority entities collapsed first.
+    pub fn set_overflow_priority(&mut self, entity: Entity, value: i32) {
+        self.store.overflow_priority.insert(entity, value);
+    }
+
+    /// Set the horizontal scroll offset of the given entity in pixels.
+    pub fn set_scroll_x(&mut self, entity: Entity, value: f32) {
+        self.store.scroll_x.insert(entity, value);
+    }
+
+    /// Set the vertical scroll offset of the given entity in pixels.
+    pub fn set_scroll_y(&mut self, entity: Entity, value: f32) {
+        self.store.scroll_y.insert(entity, value);
+    }
+
+    /// Set the items of the given entity, making it a virtual list.
+    pub fn set_virtual_list(&mut self, entity: Entity, value: VirtualList) {
+        self.store.virtual_list.insert(entity, value);
+    }
+
     /// Set the desired width of the given entity.
     pub fn set_width(&mut self, entity: Entity, value: Units) {
         self.store.width.insert(entity, value);
@@ -118,6 +156,16 @@ impl World {
         self.store.bottom.insert(entity, value);
     }
 
+    /// Set the horizontal translation of the given entity.
+    pub fn set_translate_x(&mut self, entity: Entity, value: Units) {
+        self.store.translate_x.insert(entity, value);
+    }
+
+    /// Set the vertical translation of the given entity.
+    pub fn set_translate_y(&mut self, entity: Entity, value: Units) {
+        self.store.translate_y.insert(entity, value);
+    }
+
     /// Set the desired child_space of the given entity.
     pub fn set_child_space(&mut self, entity: Entity, value: Units) {
         self.store.child_left.insert(entity, value);
@@ -156,6 +204,111 @@ impl World {
         self.store.col_between.insert(entity, value);
     }
 
+    /// Set the number of columns of the given multi-column entity.
+    pub fn set_column_count(&mut self, entity: Entity, value: usize) {
+        self.store.column_count.insert(entity, value);
+    }
+
+    /// Set the minimum column width of the given multi-column entity.
+    pub fn set_min_column_width(&mut self, entity: Entity, value: Units) {
+        self.store.min_column_width.insert(entity, value);
+    }
+
+    /// Set the start angle, in degrees clockwise from the top, of the given radial entity.
+    pub fn set_start_angle(&mut self, entity: Entity, value: f32) {
+        self.store.start_angle.insert(entity, value);
+    }
+
+    /// Set the angular span, in degrees, of the given radial entity.
+    pub fn set_angular_span(&mut self, entity: Entity, value: f32) {
+        self.store.angular_span.insert(entity, value);
+    }
+
+    /// Set the radius of the given radial entity.
+    pub fn set_radius(&mut self, entity: Entity, value: Units) {
+        self.store.radius.insert(entity, value);
+    }
+
+    /// Set the edge to which the given entity is attached within a dock parent.
+    pub fn set_dock(&mut self, entity: Entity, value: Dock) {
+        self.store.dock.insert(entity, value);
+    }
+
+    /// Set the minimum child_left space of the given entity.
+    pub fn set_min_child_left(&mut self, entity: Entity, value: Units) {
+        self.store.min_child_left.insert(entity, value);
+    }
+
+    /// Set the maximum child_left space of the given entity.
+    pub fn set_max_child_left(&mut self, entity: Entity, value: Units) {
+        self.store.max_child_left.insert(entity, value);
+    }
+
+    /// Set the minimum child_right space of the given entity.
+    pub fn set_min_child_right(&mut self, entity: Entity, value: Units) {
+        self.store.min_child_right.insert(entity, value);
+    }
+
+    /// Set the maximum child_right space of the given entity.
+    pub fn set_max_child_right(&mut self, entity: Entity, value: Units) {
+        self.store.max_child_right.insert(entity, value);
+    }
+
+    /// Set the minimum child_top space of the given entity.
+    pub fn set_min_child_top(&mut self, entity: Entity, value: Units) {
+        self.store.min_child_top.insert(entity, value);
+    }
+
+    /// Set the maximum child_top space of the given entity.
+    pub fn set_max_child_top(&mut self, entity: Entity, value: Units) {
+        self.store.max_child_top.insert(entity, value);
+    }
+
+    /// Set the minimum child_bottom space of the given entity.
+    pub fn set_min_child_bottom(&mut self, entity: Entity, value: Units) {
+        self.store.min_child_bottom.insert(entity, value);
+    }
+
+    /// Set the maximum child_bottom space of the given entity.
+    pub fn set_max_child_bottom(&mut self, entity: Entity, value: Units) {
+        self.store.max_child_bottom.insert(entity, value);
+    }
+
+    /// Set the minimum vertical (row) space between children of the given entity.
+    pub fn set_min_row_between(&mut self, entity: Entity, value: Units) {
+        self.store.min_row_between.insert(entity, value);
+    }
+
+    /// Set the maximum vertical (row) space between children of the given entity.
+    pub fn set_max_row_between(&mut self, entity: Entity, value: Units) {
+        self.store.max_row_between.insert(entity, value);
+    }
+
+    /// Set the minimum horizontal (column) space between children of the given entity.
+    pub fn set_min_col_between(&mut self, entity: Entity, value: Units) {
+        self.store.min_col_between.insert(entity, value);
+    }
+
+    /// Set the maximum horizontal (column) space between children of the given entity.
+    pub fn set_max_col_between(&mut self, entity: Entity, value: Units) {
+        self.store.max_col_between.insert(entity, value);
+    }
+
+    /// Set the alignment of the children of the given entity along its main axis.
+    pub fn set_main_alignment(&mut self, entity: Entity, value: MainAlignment) {
+        self.store.main_alignment.insert(entity, value);
+    }
+
+    /// Set the alignment of the children of the given entity along its cross axis.
+    pub fn set_cross_alignment(&mut self, entity: Entity, value: CrossAlignment) {
+        self.store.cross_alignment.insert(entity, value);
+    }
+
+    /// Set the alignment of the given entity along the cross axis of its parent.
+    pub fn set_align_self(&mut self, entity: Entity, value: CrossAlignment) {
+        self.store.align_self.insert(entity, value);
+    }
+
     /// Set the minimum left space of the given entity.
     pub fn set_min_left(&mut self, entity: Entity, value: Units) {
         self.store.min_left.insert(entity, value);
@@ -205,8 +358,33 @@ impl World {
         self.store.content_size.insert(entity, Box::new(content));
     }
 
-    pub fn set_visibility(&mut self, entity: Entity, visible: bool) {
-        self.store.visible.insert(entity, visible);
+    /// Set how the content size of the given entity is combined with the size of its children.
+    pub fn set_content_size_mode(&mut self, entity: Entity, value: ContentSizeMode) {
+        self.store.content_size_mode.insert(entity, value);
+    }
+
+    /// Set whether the children of the given entity are given the same size.
+    pub fn set_uniform_children(&mut self, entity: Entity, value: UniformChildren) {
+        self.store.uniform_children.insert(entity, value);
+    }
+
+    /// Set the shared size group of the given entity.
+    pub fn set_size_group(&mut self, entity: Entity, value: u32) {
+        self.store.size_group.insert(entity, value);
+    }
+
+    /// Set the breakpoint function for the given entity, which overrides its properties depending on the space available to it.
+    pub fn set_breakpoint(
+        &mut self,
+        entity: Entity,
+        breakpoint: impl Fn(&LayoutQuery) -> Option<Breakpoint> + 'static,
+    ) {
+        self.store.breakpoint.insert(entity, Box::new(breakpoint));
+    }
+
+    /// Set the visibility of the given entity.
+    pub fn set_visibility(&mut self, entity: Entity, value: Visibility) {
+        self.store.visibility.insert(entity, value);
     }
 
     /// Set the text to be displayed on the given entity.
@@ -235,4 +413,42 @@ impl World {
         self.store.border_top.insert(entity, width);
         self.store.border_bottom.insert(entity, width);
     }
+
+    /// Set the box sizing of the given entity.
+    pub fn set_box_sizing(&mut self, entity: Entity, value: BoxSizing) {
+        self.store.box_sizing.insert(entity, value);
+    }
+
+    /// Set the padding on all sides of the given entity.
+    pub fn set_padding(&mut self, entity: Entity, value: Units) {
+        self.store.padding_left.insert(entity, value);
+        self.store.padding_right.insert(entity, value);
+        self.store.padding_top.insert(entity, value);
+        self.store.padding_bottom.insert(entity, value);
+    }
+
+    /// Set the left padding of the given entity.
+    pub fn set_padding_left(&mut self, entity: Entity, value: Units) {
+        self.store.padding_left.insert(entity, value);
+    }
+
+    /// Set the right padding of the given entity.
+    pub fn set_padding_right(&mut self, entity: Entity, value: Units) {
+        self.store.padding_right.insert(entity, value);
+    }
+
+    /// Set the top padding of the given entity.
+    pub fn set_padding_top(&mut self, entity: Entity, value: Units) {
+        self.store.padding_top.insert(entity, value);
+    }
+
+    /// Set the bottom padding of the given entity.
+    pub fn set_padding_bottom(&mut self, entity: Entity, value: Units) {
+        self.store.padding_bottom.insert(entity, value);
+    }
+
+    /// Set the font size of the given entity.
+    pub fn set_font_size(&mut self, entity: Entity, value: f32) {
+        self.store.font_size.insert(entity, value);
+    }
 }
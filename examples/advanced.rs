@@ -16,6 +16,7 @@ use femtovg::{renderer::OpenGl, Align, Baseline, Canvas, Color, FontId, Paint, P
 
 use std::collections::HashMap;
 use std::num::NonZeroU32;
+use std::ops::Range;
 
 use morphorm::Units::*;
 use morphorm::*;
@@ -84,8 +85,8 @@ impl Node for Widget {
         self.id
     }
 
-    fn visible(&self, _store: &Self::Store) -> bool {
-        true
+    fn visibility(&self, _store: &Self::Store) -> Option<Visibility> {
+        Some(Visibility::Visible)
     }
 
     fn width(&self, _store: &Self::Store) -> Option<Units> {
@@ -120,6 +121,34 @@ impl Node for Widget {
         Some(self.position_type)
     }
 
+    fn contributes_to_size(&self, _store: &Self::Store) -> Option<bool> {
+        None
+    }
+
+    fn order(&self, _store: &Self::Store) -> Option<i32> {
+        None
+    }
+
+    fn overflow(&self, _store: &Self::Store) -> Option<morphorm::Overflow> {
+        None
+    }
+
+    fn overflow_priority(&self, _store: &Self::Store) -> Option<i32> {
+        None
+    }
+
+    fn scroll_x(&self, _store: &Self::Store) -> Option<f32> {
+        None
+    }
+
+    fn scroll_y(&self, _store: &Self::Store) -> Option<f32> {
+        None
+    }
+
+    fn virtual_list(&self, _store: &Self::Store) -> Option<VirtualList> {
+        None
+    }
+
     fn left(&self, _store: &Self::Store) -> Option<Units> {
         Some(self.left)
     }
@@ -136,6 +165,14 @@ impl Node for Widget {
         Some(self.bottom)
     }
 
+    fn translate_x(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn translate_y(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
     fn content_size<'a>(
         &self,
         _store: &Self::Store,
@@ -146,6 +183,22 @@ impl Node for Widget {
         None
     }
 
+    fn content_size_mode(&self, _store: &Self::Store) -> Option<ContentSizeMode> {
+        None
+    }
+
+    fn uniform_children(&self, _store: &Self::Store) -> Option<UniformChildren> {
+        None
+    }
+
+    fn size_group(&self, _store: &Self::Store) -> Option<u32> {
+        None
+    }
+
+    fn breakpoint(&self, _store: &Self::Store, _query: &LayoutQuery) -> Option<Breakpoint> {
+        None
+    }
+
     fn child_left(&self, _store: &Self::Store) -> Option<Units> {
         Some(self.child_left)
     }
@@ -170,6 +223,90 @@ impl Node for Widget {
         Some(self.col_between)
     }
 
+    fn column_count(&self, _store: &Self::Store) -> Option<usize> {
+        None
+    }
+
+    fn min_column_width(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn start_angle(&self, _store: &Self::Store) -> Option<f32> {
+        None
+    }
+
+    fn angular_span(&self, _store: &Self::Store) -> Option<f32> {
+        None
+    }
+
+    fn radius(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn dock(&self, _store: &Self::Store) -> Option<Dock> {
+        None
+    }
+
+    fn min_child_left(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn min_child_right(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn min_child_top(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn min_child_bottom(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn max_child_left(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn max_child_right(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn max_child_top(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn max_child_bottom(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn min_row_between(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn max_row_between(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn min_col_between(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn max_col_between(&self, _store: &Self::Store) -> Option<Units> {
+        None
+    }
+
+    fn main_alignment(&self, _store: &Self::Store) -> Option<MainAlignment> {
+        None
+    }
+
+    fn cross_alignment(&self, _store: &Self::Store) -> Option<CrossAlignment> {
+        None
+    }
+
+    fn align_self(&self, _store: &Self::Store) -> Option<CrossAlignment> {
+        None
+    }
+
     fn min_left(&self, _store: &Self::Store) -> Option<Units> {
         Some(self.min_left)
     }
@@ -217,11 +354,36 @@ impl Node for Widget {
     fn border_bottom(&self, _store: &Self::Store) -> Option<Units> {
         Some(Units::Pixels(0.0))
     }
+
+    fn box_sizing(&self, _store: &Self::Store) -> Option<BoxSizing> {
+        None
+    }
+
+    fn padding_left(&self, _store: &Self::Store) -> Option<Units> {
+        Some(Units::Pixels(0.0))
+    }
+
+    fn padding_right(&self, _store: &Self::Store) -> Option<Units> {
+        Some(Units::Pixels(0.0))
+    }
+
+    fn padding_top(&self, _store: &Self::Store) -> Option<Units> {
+        Some(Units::Pixels(0.0))
+    }
+
+    fn padding_bottom(&self, _store: &Self::Store) -> Option<Units> {
+        Some(Units::Pixels(0.0))
+    }
+
+    fn font_size(&self, _store: &Self::Store) -> Option<f32> {
+        None
+    }
 }
 
 #[derive(Default)]
 pub struct LayoutCache {
     rect: HashMap<u32, (f32, f32, f32, f32)>,
+    visible_range: HashMap<u32, Range<usize>>,
 }
 
 impl Cache for LayoutCache {
@@ -269,6 +431,14 @@ impl Cache for LayoutCache {
 
         0.0
     }
+
+    fn visible_range(&self, node: &Self::Node) -> Option<Range<usize>> {
+        self.visible_range.get(&node.key()).cloned()
+    }
+
+    fn set_visible_range(&mut self, node: &Self::Node, range: Range<usize>) {
+        self.visible_range.insert(node.key(), range);
+    }
 }
 
 fn main() {
@@ -108,17 +108,13 @@ pub fn render(mut world: World, root: Entity) {
                     surface.resize(&context, size.width.try_into().unwrap(), size.height.try_into().unwrap());
 
                     let layout_type = world.store.layout_type.get(root).cloned().unwrap_or_default();
-                    match layout_type {
-                        LayoutType::Row => {
-                            world.set_width(root, Units::Pixels(size.width as f32));
-                            world.set_height(root, Units::Pixels(size.height as f32));
-                        }
-
-                        LayoutType::Column => {
-                            world.set_height(root, Units::Pixels(size.height as f32));
-                            world.set_width(root, Units::Pixels(size.width as f32));
-                        }
-                    };
+                    if layout_type.is_row() {
+                        world.set_width(root, Units::Pixels(size.width as f32));
+                        world.set_height(root, Units::Pixels(size.height as f32));
+                    } else {
+                        world.set_height(root, Units::Pixels(size.height as f32));
+                        world.set_width(root, Units::Pixels(size.width as f32));
+                    }
 
                     root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
                 }
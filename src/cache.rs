@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use crate::{LayoutType, Node};
 
 /// The `Cache` is a store which contains the computed size and position of nodes
@@ -20,6 +22,42 @@ pub trait Cache {
 
     /// Sets the cached position and size of the given node.
     fn set_bounds(&mut self, node: &Self::Node, posx: f32, posy: f32, width: f32, height: f32);
+
+    /// Clears the cached position and size of the given node.
+    ///
+    /// This is called during layout for nodes which are [`Collapsed`](crate::Visibility::Collapsed), and for all of
+    /// their descendants, so that the bounds from a previous layout do not remain in the cache.
+    /// By default the node is given a zero-sized rect at the origin of its parent.
+    fn clear_bounds(&mut self, node: &Self::Node) {
+        self.set_bounds(node, 0.0, 0.0, 0.0, 0.0);
+    }
+
+    /// Sets whether the given node has been collapsed because it does not fit within its parent.
+    ///
+    /// This is called during layout for every parent-directed child of a node, so that the collapsed children
+    /// of a parent which [collapses](crate::Overflow::Collapse) overflowing children can be shown elsewhere,
+    /// e.g. in an overflow menu. Collapsed children also have their bounds cleared. Does nothing by default.
+    fn set_overflowed(&mut self, _node: &Self::Node, _overflowed: bool) {}
+
+    /// Sets the content extent of the given node.
+    ///
+    /// This is called during layout for every node which is not collapsed, with the width and height of the node
+    /// expanded to cover the space and size of its children, before they are translated by the scroll offset.
+    /// This can be used to size the scrollbars of a [scrolling](crate::Overflow::Scroll) node. Does nothing by default.
+    fn set_content_extent(&mut self, _node: &Self::Node, _width: f32, _height: f32) {}
+
+    /// Returns the range of items laid out for the given [virtual list](crate::VirtualList), as set by
+    /// [`set_visible_range`](Cache::set_visible_range), or `None` if the node has not been laid out as a virtual list.
+    ///
+    /// This is used to clear the cached bounds of the items which have left the visible window of the list since the
+    /// previous layout, without visiting the items which remain outside of it.
+    fn visible_range(&self, node: &Self::Node) -> Option<Range<usize>>;
+
+    /// Sets the range of items laid out for the given [virtual list](crate::VirtualList).
+    ///
+    /// This is called during layout for every virtual list, with the items within its visible window and the overscan
+    /// around it, which can also be used to only render or recycle those items.
+    fn set_visible_range(&mut self, node: &Self::Node, range: Range<usize>);
 }
 
 /// Helper trait for getting/setting node position/size in a direction agnostic way.
@@ -33,9 +71,10 @@ pub(crate) trait CacheExt: Cache {
         main: f32,
         cross: f32,
     ) {
-        match parent_layout_type {
-            LayoutType::Row => self.set_bounds(node, main_pos, cross_pos, main, cross),
-            LayoutType::Column => self.set_bounds(node, cross_pos, main_pos, cross, main),
+        if parent_layout_type.is_row() {
+            self.set_bounds(node, main_pos, cross_pos, main, cross)
+        } else {
+            self.set_bounds(node, cross_pos, main_pos, cross, main)
         }
     }
 }
@@ -1,10 +1,16 @@
+use std::collections::HashMap;
+
 use smallvec::SmallVec;
 
-use crate::{Cache, CacheExt, LayoutType, Node, NodeExt, PositionType, Size, Units::*};
+use crate::{
+    BoxSizing, Breakpoint, Cache, CacheExt, ContentSizeMode, Dock, LayoutContext, LayoutQuery, LayoutType, Node,
+    NodeExt, Overflow, PositionType, Size, UniformChildren, Units::*, Visibility,
+};
 
 const DEFAULT_MIN: f32 = -f32::MAX;
 const DEFAULT_MAX: f32 = f32::MAX;
 const DEFAULT_BORDER_WIDTH: f32 = 0.0;
+const DEFAULT_PADDING: f32 = 0.0;
 
 /// Represents the type of a stretch item. Either space-before, size (main/cross), or space-after.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -45,6 +51,8 @@ impl StretchItem {
 struct ChildNode<'a, N: Node> {
     // A reference to the node.
     node: &'a N,
+    // The breakpoint of the node, evaluated by its parent.
+    breakpoint: Breakpoint,
     // Computed cross-before space of the node.
     cross_before: f32,
     // Computed cross size of the node.
@@ -57,6 +65,8 @@ struct ChildNode<'a, N: Node> {
     main: f32,
     // Computed main-after space of the node.
     main_after: f32,
+    // The size of the node when it was laid out to determine whether it overflows its parent.
+    measured: Option<Size>,
 }
 
 /// Performs layout on the given node returning its computed size.
@@ -74,11 +84,13 @@ struct ChildNode<'a, N: Node> {
 /// * `tree` - A mutable reference to the [`Tree`](crate::Node::Tree).
 /// * `store` - A mutable reference to the [`Store`](crate::Node::Store).
 /// * `sublayout` - A mutable reference to the [`SubLayout`](crate::Node::SubLayout) context.
+/// * `ctx` - A reference to the [`LayoutContext`] holding the values used to resolve units, the constraints given
+///   to the node by its parent, and the state shared by the whole layout.
 ///
 /// # Example
 ///
 /// ```
-/// layout(&root, LayoutType::Column, 600.0, 600.0, &mut cache, &tree, &store, &mut sublayout);
+/// layout(&root, LayoutType::Column, 600.0, 600.0, &mut cache, &tree, &store, &mut sublayout, &ctx);
 /// ```
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn layout<N, C>(
@@ -90,75 +102,222 @@ pub(crate) fn layout<N, C>(
     tree: &<N as Node>::Tree,
     store: &<N as Node>::Store,
     sublayout: &mut <N as Node>::SubLayout<'_>,
+    ctx: &LayoutContext<N::CacheKey>,
 ) -> Size
 where
     N: Node,
     C: Cache<Node = N>,
 {
-    // The layout type of the node. Determines the main and cross axes of the children.
-    let layout_type = node.layout_type(store).unwrap_or_default();
+    // Whether the size of the parent is not yet known, in which case percentage sizes of the node are treated as auto.
+    let (parent_indefinite_main, parent_indefinite_cross) = (ctx.indefinite_main, ctx.indefinite_cross);
+
+    // The size given to the node by a parent with uniform children.
+    let (uniform_main, uniform_cross) = (ctx.uniform_main, ctx.uniform_cross);
+
+    // The breakpoint of the node, evaluated once against the space available to it, unless its parent has already
+    // evaluated it.
+    let breakpoint = ctx.breakpoint.unwrap_or_else(|| node.resolved_breakpoint(store, ctx));
+
+    // The context used to resolve the units of the node and its children, with the font size of the node,
+    // which is inherited from the parent if not set.
+    let ctx = &LayoutContext {
+        font_size: node.font_size(store).unwrap_or(ctx.font_size),
+        indefinite_main: false,
+        indefinite_cross: false,
+        uniform_main: None,
+        uniform_cross: None,
+        breakpoint: None,
+        ..*ctx
+    };
+
+    // The layout type of the node. Determines how the children are arranged.
+    let node_layout_type = node.resolved_layout_type(store, &breakpoint);
+
+    // The layout type which determines the main and cross axes of the children. Children which are not arranged into
+    // a row have a vertical main-axis.
+    let layout_type = if node_layout_type.is_row() { LayoutType::Row } else { LayoutType::Column };
 
     // The desired main-axis and cross-axis sizes of the node.
-    let main = node.main(store, parent_layout_type);
-    let cross = node.cross(store, parent_layout_type);
+    let mut main = node.main(store, parent_layout_type, ctx, &breakpoint);
+    let mut cross = node.cross(store, parent_layout_type, ctx, &breakpoint);
+
+    if parent_indefinite_main && main.is_percentage_based() {
+        main = Auto;
+    }
+
+    if parent_indefinite_cross && cross.is_percentage_based() {
+        cross = Auto;
+    }
 
     let mut min_main = if main.is_stretch() {
         DEFAULT_MIN
     } else {
-        node.min_main(store, parent_layout_type).to_px(parent_main, DEFAULT_MIN)
+        node.min_main(store, parent_layout_type, ctx).to_px(parent_main, DEFAULT_MIN)
     };
 
-    let max_main = if main.is_stretch() {
-        DEFAULT_MAX
-    } else {
-        node.max_main(store, parent_layout_type).to_px(parent_main, DEFAULT_MAX)
+    let mut max_main = match node.max_main(store, parent_layout_type, ctx) {
+        _ if main.is_stretch() => DEFAULT_MAX,
+        max if parent_indefinite_main && max.is_percentage_based() => DEFAULT_MAX,
+        max => max.to_px(parent_main, DEFAULT_MAX),
     };
 
     let mut min_cross = if cross.is_stretch() {
         DEFAULT_MIN
     } else {
-        node.min_cross(store, parent_layout_type).to_px(parent_cross, DEFAULT_MIN)
+        node.min_cross(store, parent_layout_type, ctx).to_px(parent_cross, DEFAULT_MIN)
     };
 
-    let max_cross = if cross.is_stretch() {
-        DEFAULT_MAX
-    } else {
-        node.max_cross(store, parent_layout_type).to_px(parent_cross, DEFAULT_MAX)
+    let mut max_cross = match node.max_cross(store, parent_layout_type, ctx) {
+        _ if cross.is_stretch() => DEFAULT_MAX,
+        max if parent_indefinite_cross && max.is_percentage_based() => DEFAULT_MAX,
+        max => max.to_px(parent_cross, DEFAULT_MAX),
     };
 
     // Compute main-axis size.
     let mut computed_main = match main {
         Pixels(val) => val,
         Percentage(val) => (parent_main * (val / 100.0)).round(),
+        Calc { px, percent } => (px + parent_main * (percent / 100.0)).round(),
         Stretch(_) => parent_main,
         Auto => 0.0,
+        // Viewport and font-relative units are already converted to pixels when retrieved from the node, so resolving
+        // them again only keeps the match exhaustive and leaves the size unchanged.
+        ViewportWidth(_) | ViewportHeight(_) | Em(_) | Rem(_) => {
+            main.resolve(ctx, node.font_size(store)).to_px(parent_main, 0.0)
+        }
     };
 
     // Compute cross-axis size.
     let mut computed_cross = match cross {
         Pixels(val) => val,
         Percentage(val) => (parent_cross * (val / 100.0)).round(),
+        Calc { px, percent } => (px + parent_cross * (percent / 100.0)).round(),
         Stretch(_) => parent_cross,
         Auto => 0.0,
+        // Viewport and font-relative units are already converted to pixels when retrieved from the node, so resolving
+        // them again only keeps the match exhaustive and leaves the size unchanged.
+        ViewportWidth(_) | ViewportHeight(_) | Em(_) | Rem(_) => {
+            cross.resolve(ctx, node.font_size(store)).to_px(parent_cross, 0.0)
+        }
     };
 
+    // Compute the border and padding, with percentages resolved against the content box of the parent.
     let border_main_before =
-        node.border_main_before(store, parent_layout_type).to_px(computed_main, DEFAULT_BORDER_WIDTH);
+        node.border_main_before(store, parent_layout_type, ctx).to_px(ctx.parent_main, DEFAULT_BORDER_WIDTH);
     let border_main_after =
-        node.border_main_after(store, parent_layout_type).to_px(computed_main, DEFAULT_BORDER_WIDTH);
+        node.border_main_after(store, parent_layout_type, ctx).to_px(ctx.parent_main, DEFAULT_BORDER_WIDTH);
     let border_cross_before =
-        node.border_cross_before(store, parent_layout_type).to_px(computed_cross, DEFAULT_BORDER_WIDTH);
+        node.border_cross_before(store, parent_layout_type, ctx).to_px(ctx.parent_cross, DEFAULT_BORDER_WIDTH);
     let border_cross_after =
-        node.border_cross_after(store, parent_layout_type).to_px(computed_cross, DEFAULT_BORDER_WIDTH);
+        node.border_cross_after(store, parent_layout_type, ctx).to_px(ctx.parent_cross, DEFAULT_BORDER_WIDTH);
+
+    let padding_main_before =
+        node.padding_main_before(store, parent_layout_type, ctx).to_px(ctx.parent_main, DEFAULT_PADDING);
+    let padding_main_after =
+        node.padding_main_after(store, parent_layout_type, ctx).to_px(ctx.parent_main, DEFAULT_PADDING);
+    let padding_cross_before =
+        node.padding_cross_before(store, parent_layout_type, ctx).to_px(ctx.parent_cross, DEFAULT_PADDING);
+    let padding_cross_after =
+        node.padding_cross_after(store, parent_layout_type, ctx).to_px(ctx.parent_cross, DEFAULT_PADDING);
+
+    // The space between the edges of the node and its content box, made up of the border and padding.
+    let inset_main_before = border_main_before + padding_main_before;
+    let inset_main_after = border_main_after + padding_main_after;
+    let inset_cross_before = border_cross_before + padding_cross_before;
+    let inset_cross_after = border_cross_after + padding_cross_after;
+
+    // With content-box sizing, fixed sizes and size constraints apply to the content box of the node,
+    // so the border and padding are added to them.
+    if node.box_sizing(store).unwrap_or_default() == BoxSizing::ContentBox {
+        if !main.is_stretch() {
+            if !main.is_auto() {
+                computed_main += inset_main_before + inset_main_after;
+            }
+            min_main += inset_main_before + inset_main_after;
+            max_main += inset_main_before + inset_main_after;
+        }
+
+        if !cross.is_stretch() {
+            if !cross.is_auto() {
+                computed_cross += inset_cross_before + inset_cross_after;
+            }
+            min_cross += inset_cross_before + inset_cross_after;
+            max_cross += inset_cross_before + inset_cross_after;
+        }
+    }
+
+    // Whether the size of the node is determined by its content and children, measured for its shared size group.
+    let auto_main = main.is_auto() && uniform_main.is_none();
+    let auto_cross = cross.is_auto() && uniform_cross.is_none();
+
+    // An auto-sized node in a shared size group takes the size of the largest auto-sized node in the group.
+    if let Some(&(group_width, group_height)) = node.size_group(store).and_then(|id| ctx.size_groups.get(&id)) {
+        let (group_main, group_cross) =
+            if parent_layout_type.is_row() { (group_width, group_height) } else { (group_height, group_width) };
+
+        if main.is_auto() {
+            main = Pixels(group_main);
+            computed_main = group_main;
+        }
+
+        if cross.is_auto() {
+            cross = Pixels(group_cross);
+            computed_cross = group_cross;
+        }
+    }
+
+    // A child of a node with uniform children takes the size given to it by its parent, within its size constraints.
+    if let Some(uniform_main) = uniform_main {
+        main = Pixels(uniform_main);
+        computed_main = uniform_main;
+    }
+
+    if let Some(uniform_cross) = uniform_cross {
+        cross = Pixels(uniform_cross);
+        computed_cross = uniform_cross;
+    }
+
+    // The context used to evaluate the breakpoints of the children, with the size of the content box of the node.
+    // If the size of the node is determined by its children, this is its size before they are laid out.
+    let ctx = &LayoutContext {
+        query: {
+            let query_main =
+                (computed_main.max(min_main).min(max_main) - inset_main_before - inset_main_after).max(0.0);
+            let query_cross =
+                (computed_cross.max(min_cross).min(max_cross) - inset_cross_before - inset_cross_after).max(0.0);
+            if parent_layout_type.is_row() {
+                LayoutQuery { parent_width: query_main, parent_height: query_cross }
+            } else {
+                LayoutQuery { parent_width: query_cross, parent_height: query_main }
+            }
+        },
+        ..*ctx
+    };
+
+    // The items of the node if it is a virtual list, in which case only the visible children are visited.
+    let virtual_list = node.virtual_list(store);
+
+    // The children of the node in the order they are arranged, sorted by their order and then by tree order, with
+    // their breakpoints evaluated against the content box of the node. The children of a virtual list are visited
+    // separately, so that only its visible children are evaluated.
+    let mut ordered_children = if virtual_list.is_some() {
+        SmallVec::<[(&N, Breakpoint); 32]>::new()
+    } else {
+        node.children(tree).map(|child| (child, child.resolved_breakpoint(store, ctx))).collect()
+    };
+    ordered_children.sort_by_key(|(child, _)| child.order(store).unwrap_or_default());
 
     // Get the total number of children of the node.
-    let num_children = node.children(tree).filter(|child| child.visible(store)).count();
+    let num_children = ordered_children
+        .iter()
+        .filter(|(child, breakpoint)| child.resolved_visibility(store, breakpoint) != Visibility::Collapsed)
+        .count();
 
     // Get the total number of parent-directed children of the node.
-    let num_parent_directed_children = node
-        .children(tree)
-        .filter(|child| child.position_type(store).unwrap_or_default() == PositionType::ParentDirected)
-        .filter(|child| child.visible(store))
+    let num_parent_directed_children = ordered_children
+        .iter()
+        .filter(|(child, _)| child.position_type(store).unwrap_or_default() != PositionType::SelfDirected)
+        .filter(|(child, breakpoint)| child.resolved_visibility(store, breakpoint) != Visibility::Collapsed)
         .count();
 
     // Sum of all child nodes on the main-axis.
@@ -167,26 +326,48 @@ where
     // Maximum of all child nodes on the cross-axis.
     let mut cross_max = 0.0f32;
 
-    // Apply content sizing.
-    if (main.is_auto() || cross.is_auto()) && num_parent_directed_children == 0 {
-        let p_main = if main.is_auto() { None } else { Some(computed_main) };
-        let p_cross = if cross.is_auto() { None } else { Some(computed_cross) };
+    // Determine whether the content size is used, which by default is only when the node has no parent-directed children.
+    let content_size_mode = node.content_size_mode(store).unwrap_or_default();
+    let use_content_size = num_parent_directed_children == 0 || content_size_mode != ContentSizeMode::Exclusive;
+
+    // The content size of the node, which is combined with the space and size of its children.
+    let mut content_size_main = 0.0f32;
+    let mut content_size_cross = 0.0f32;
+
+    // Apply content sizing. The content size is the size of the content box, so the border and padding are added.
+    if (main.is_auto() || cross.is_auto()) && use_content_size {
+        let p_main = if main.is_auto() { None } else { Some(computed_main - inset_main_before - inset_main_after) };
+        let p_cross =
+            if cross.is_auto() { None } else { Some(computed_cross - inset_cross_before - inset_cross_after) };
 
         if let Some(content_size) = node.content_sizing(store, sublayout, parent_layout_type, p_main, p_cross) {
-            computed_main = content_size.0;
-            computed_cross = content_size.1;
+            computed_main = content_size.0 + inset_main_before + inset_main_after;
+            computed_cross = content_size.1 + inset_cross_before + inset_cross_after;
+            content_size_main = content_size.0;
+            content_size_cross = content_size.1;
         }
     }
 
-    if (node.min_main(store, parent_layout_type).is_auto() || node.min_cross(store, parent_layout_type).is_auto())
-        && num_parent_directed_children == 0
+    if (node.min_main(store, parent_layout_type, ctx).is_auto()
+        || node.min_cross(store, parent_layout_type, ctx).is_auto())
+        && use_content_size
     {
-        let p_main = if node.min_main(store, parent_layout_type).is_auto() { None } else { Some(computed_main) };
-        let p_cross = if node.min_cross(store, parent_layout_type).is_auto() { None } else { Some(computed_cross) };
+        let p_main = if node.min_main(store, parent_layout_type, ctx).is_auto() {
+            None
+        } else {
+            Some(computed_main - inset_main_before - inset_main_after)
+        };
+        let p_cross = if node.min_cross(store, parent_layout_type, ctx).is_auto() {
+            None
+        } else {
+            Some(computed_cross - inset_cross_before - inset_cross_after)
+        };
 
         if let Some(content_size) = node.content_sizing(store, sublayout, parent_layout_type, p_main, p_cross) {
-            min_main = content_size.0;
-            min_cross = content_size.1;
+            min_main = content_size.0 + inset_main_before + inset_main_after;
+            min_cross = content_size.1 + inset_cross_before + inset_cross_after;
+            content_size_main = content_size_main.max(content_size.0);
+            content_size_cross = content_size_cross.max(content_size.1);
         }
     }
 
@@ -201,13 +382,865 @@ where
     //     return Size { main: computed_main, cross: computed_cross };
     // }
 
-    // Determine the parent_main/cross size to pass to the children based on the layout type of the parent and the node.
+    // Determine the offsets of the content box from the edges of the node on the axes of the children.
     // i.e. if the parent layout type and the node layout type are different, swap the main and the cross axes.
+    let (content_main_before, content_main_after, content_cross_before, content_cross_after) =
+        if parent_layout_type == layout_type {
+            (inset_main_before, inset_main_after, inset_cross_before, inset_cross_after)
+        } else {
+            (inset_cross_before, inset_cross_after, inset_main_before, inset_main_after)
+        };
+
+    // Determine the parent_main/cross size to pass to the children based on the layout type of the parent and the node.
+    // This is the size of the content box of the node, which is also the basis for the percentages of the children.
     let (mut parent_main, mut parent_cross) = if parent_layout_type == layout_type {
         (computed_main, computed_cross)
     } else {
         (computed_cross, computed_main)
     };
+    parent_main = (parent_main - content_main_before - content_main_after).max(0.0);
+    parent_cross = (parent_cross - content_cross_before - content_cross_after).max(0.0);
+
+    // Determine whether the size of the content box of the node, on the axes of the children, is unknown until the
+    // parent-directed children are laid out. If so, percentages of the children are resolved once the size is known.
+    let (indefinite_main, indefinite_cross) = if parent_layout_type == layout_type {
+        (main.is_auto(), cross.is_auto())
+    } else {
+        (cross.is_auto(), main.is_auto())
+    };
+
+    // Determine the content size on the axes of the children.
+    let (content_size_main, content_size_cross) = if parent_layout_type == layout_type {
+        (content_size_main, content_size_cross)
+    } else {
+        (content_size_cross, content_size_main)
+    };
+
+    // The content is placed before the children when it is treated as an implicit first child.
+    let content_size_before = if content_size_mode == ContentSizeMode::FirstChild { content_size_main } else { 0.0 };
+
+    // Determine how the node handles children which do not fit within it.
+    let overflow = node.overflow(store).unwrap_or_default();
+
+    // Determine the scroll offset on the axes of the children, which translates the children of a scrolling node.
+    let (scroll_main, scroll_cross) = if overflow == Overflow::Scroll {
+        let scroll_x = node.scroll_x(store).unwrap_or_default();
+        let scroll_y = node.scroll_y(store).unwrap_or_default();
+        if layout_type.is_row() {
+            (scroll_x, scroll_y)
+        } else {
+            (scroll_y, scroll_x)
+        }
+    } else {
+        (0.0, 0.0)
+    };
+
+    // Lay out a virtual list, with the children of the node as its items. Only the children within the visible window
+    // of the node, which is its content box offset by its scroll offset, and the overscan around it are laid out.
+    if let Some(list) = virtual_list {
+        let total_main = list.count as f32 * list.item_extent;
+
+        // Determine the auto size of the node on the main-axis of the children from the total extent of the items.
+        if indefinite_main {
+            if parent_layout_type == layout_type {
+                computed_main = (total_main + content_main_before + content_main_after).max(min_main).min(max_main);
+                parent_main = (computed_main - content_main_before - content_main_after).max(0.0);
+            } else {
+                computed_cross = (total_main + content_main_before + content_main_after).max(min_cross).min(max_cross);
+                parent_main = (computed_cross - content_main_before - content_main_after).max(0.0);
+            }
+        }
+
+        // Determine the range of items within the visible window.
+        let (first, last) = if list.item_extent > 0.0 {
+            let first = (scroll_main / list.item_extent).floor().max(0.0) as usize;
+            let last = ((scroll_main + parent_main) / list.item_extent).ceil().max(0.0) as usize;
+            (first.saturating_sub(list.overscan).min(list.count), last.saturating_add(list.overscan).min(list.count))
+        } else {
+            (0, list.count)
+        };
+
+        // Clear the cached size and position of the items which have left the visible window since the previous layout.
+        let previous_range = cache.visible_range(node).unwrap_or_default();
+        for (_, child) in node
+            .children(tree)
+            .enumerate()
+            .skip(previous_range.start)
+            .take(previous_range.len())
+            .filter(|(index, _)| *index < first || *index >= last)
+        {
+            clear_bounds(child, cache, tree, ctx);
+        }
+        cache.set_visible_range(node, first..last);
+
+        for (index, child) in node.children(tree).enumerate().skip(first).take(last - first) {
+            let breakpoint = child.resolved_breakpoint(store, ctx);
+            if child.resolved_visibility(store, &breakpoint) == Visibility::Collapsed {
+                clear_bounds(child, cache, tree, ctx);
+                set_skipped(child, true, ctx);
+                continue;
+            }
+
+            let child_size = layout(
+                child,
+                layout_type,
+                list.item_extent,
+                parent_cross,
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext { parent_main: list.item_extent, parent_cross, breakpoint: Some(breakpoint), ..*ctx },
+            );
+
+            let child_cross_before = child.cross_before(store, layout_type, ctx).to_px(parent_cross, 0.0);
+
+            cache.set_rect(
+                child,
+                layout_type,
+                index as f32 * list.item_extent + content_main_before - scroll_main,
+                child_cross_before + content_cross_before - scroll_cross,
+                child_size.main,
+                child_size.cross,
+            );
+            set_skipped(child, false, ctx);
+            translate(child, cache, store, ctx);
+        }
+
+        // Report the content extent of the node, covering the total extent of the items.
+        report_content_extent(
+            node,
+            parent_layout_type,
+            layout_type,
+            Size { main: computed_main, cross: computed_cross },
+            total_main + content_main_before + content_main_after,
+            0.0,
+            cache,
+        );
+
+        let size = Size { main: computed_main, cross: computed_cross };
+        measure_size_group(node, store, parent_layout_type, auto_main, auto_cross, size, ctx);
+        return size;
+    }
+
+    // Flow the children of a multi-column node into columns of balanced height, filling each column from top to bottom.
+    if node_layout_type == LayoutType::MultiColumn {
+        let column_gap = node.cross_between(store, layout_type, ctx).to_px(parent_cross, 0.0);
+        let row_gap = node.main_between(store, layout_type, ctx).to_px(parent_main, 0.0);
+
+        // Determine the number of columns, either fixed or as many columns of the minimum column width as fit. A node
+        // with an auto width has a single column unless the number of columns is fixed.
+        let min_column_width = node
+            .min_column_width(store)
+            .unwrap_or_default()
+            .resolve(ctx, node.font_size(store))
+            .to_px(parent_cross, 0.0);
+        let num_columns = match node.column_count(store) {
+            Some(count) => count.max(1),
+            None if min_column_width > 0.0 && !indefinite_cross => {
+                (((parent_cross + column_gap) / (min_column_width + column_gap)).floor() as usize).max(1)
+            }
+            None => 1,
+        };
+
+        // Returns the width of a column for the given width of the content box.
+        let column_width_for =
+            |parent_cross: f32| ((parent_cross - column_gap * (num_columns - 1) as f32) / num_columns as f32).max(0.0);
+        let mut column_width = if indefinite_cross { min_column_width } else { column_width_for(parent_cross) };
+
+        // Lay out the parent-directed children within the width of a column. Self-directed children are laid out once
+        // the size of the content box is known.
+        let mut items = SmallVec::<[ChildNode<N>; 32]>::new();
+        let mut self_directed_children = SmallVec::<[(&N, Breakpoint); 32]>::new();
+        for (child, breakpoint) in ordered_children.iter().copied() {
+            if child.resolved_visibility(store, &breakpoint) == Visibility::Collapsed {
+                clear_bounds(child, cache, tree, ctx);
+                set_skipped(child, true, ctx);
+                continue;
+            }
+
+            if child.position_type(store).unwrap_or_default() == PositionType::SelfDirected {
+                self_directed_children.push((child, breakpoint));
+                continue;
+            }
+
+            let child_size = layout(
+                child,
+                layout_type,
+                parent_main,
+                column_width,
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext {
+                    parent_main,
+                    parent_cross: column_width,
+                    indefinite_main,
+                    indefinite_cross,
+                    breakpoint: Some(breakpoint),
+                    ..*ctx
+                },
+            );
+
+            items.push(ChildNode {
+                node: child,
+                breakpoint,
+                cross_before: child.cross_before(store, layout_type, ctx).to_px(column_width, 0.0),
+                cross: child_size.cross,
+                cross_after: 0.0,
+                main_before: child.main_before(store, layout_type, ctx).to_px(parent_main, 0.0),
+                main: child_size.main,
+                main_after: child.main_after(store, layout_type, ctx).to_px(parent_main, 0.0),
+                measured: None,
+            });
+        }
+
+        // Determine the auto width of the node from its columns, each as wide as the widest child and at least the
+        // minimum column width, and from its content size, then lay out the children again within the resulting width
+        // of a column.
+        if indefinite_cross {
+            let widest = items.iter().map(|child| child.cross_before + child.cross).fold(min_column_width, f32::max);
+            let columns_cross = (widest * num_columns as f32 + column_gap * (num_columns - 1) as f32)
+                .max(content_size_cross)
+                + content_cross_before
+                + content_cross_after;
+            if parent_layout_type == layout_type {
+                computed_cross = columns_cross.max(min_cross).min(max_cross);
+                parent_cross = (computed_cross - content_cross_before - content_cross_after).max(0.0);
+            } else {
+                computed_main = columns_cross.max(min_main).min(max_main);
+                parent_cross = (computed_main - content_cross_before - content_cross_after).max(0.0);
+            }
+
+            column_width = column_width_for(parent_cross);
+            for child in items.iter_mut() {
+                let child_size = layout(
+                    child.node,
+                    layout_type,
+                    parent_main,
+                    column_width,
+                    cache,
+                    tree,
+                    store,
+                    sublayout,
+                    &LayoutContext {
+                        parent_main,
+                        parent_cross: column_width,
+                        indefinite_main,
+                        breakpoint: Some(child.breakpoint),
+                        ..*ctx
+                    },
+                );
+                child.cross_before = child.node.cross_before(store, layout_type, ctx).to_px(column_width, 0.0);
+                child.cross = child_size.cross;
+                child.main = child_size.main;
+            }
+        }
+
+        // Position the self-directed children by their space relative to the content box.
+        let mut content_main_end = content_size_main;
+        let mut content_cross_end = content_size_cross;
+        let mut contributing_main = 0.0f32;
+        let mut contributing_cross = 0.0f32;
+        for (child, breakpoint) in self_directed_children {
+            let child_size = layout(
+                child,
+                layout_type,
+                parent_main,
+                parent_cross,
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext { parent_main, parent_cross, breakpoint: Some(breakpoint), ..*ctx },
+            );
+
+            let child_main_pos = child.main_before(store, layout_type, ctx).to_px(parent_main, 0.0);
+            let child_cross_pos = child.cross_before(store, layout_type, ctx).to_px(parent_cross, 0.0);
+            cache.set_rect(
+                child,
+                layout_type,
+                child_main_pos + content_main_before - scroll_main,
+                child_cross_pos + content_cross_before - scroll_cross,
+                child_size.main,
+                child_size.cross,
+            );
+            set_skipped(child, false, ctx);
+            translate(child, cache, store, ctx);
+            content_main_end = content_main_end.max(child_main_pos + child_size.main);
+            content_cross_end = content_cross_end.max(child_cross_pos + child_size.cross);
+
+            // Self-directed children which contribute to the size of the node extend its auto size, without changing
+            // the width of the columns.
+            if child.contributes_to_size(store).unwrap_or_default() {
+                let child_main_after = child.main_after(store, layout_type, ctx).to_px(parent_main, 0.0);
+                let child_cross_after = child.cross_after(store, layout_type, ctx).to_px(parent_cross, 0.0);
+                contributing_main = contributing_main.max(child_main_pos + child_size.main + child_main_after);
+                contributing_cross = contributing_cross.max(child_cross_pos + child_size.cross + child_cross_after);
+            }
+        }
+
+        if indefinite_cross {
+            let contributing_cross = contributing_cross + content_cross_before + content_cross_after;
+            if parent_layout_type == layout_type {
+                computed_cross = computed_cross.max(contributing_cross.max(min_cross).min(max_cross));
+            } else {
+                computed_main = computed_main.max(contributing_cross.max(min_main).min(max_main));
+            }
+        }
+
+        // Returns the column of each child when the columns are filled in order up to the given height, along with the
+        // number of columns used and the smallest height which would move a child into an earlier column.
+        let fill = |height: f32| {
+            let mut column_indices = SmallVec::<[usize; 32]>::with_capacity(items.len());
+            let mut column = 0;
+            let mut column_main = 0.0f32;
+            let mut next_height = f32::MAX;
+            for (index, child) in items.iter().enumerate() {
+                let child_main = child.main_before + child.main + child.main_after;
+                if index > 0 {
+                    if column_main + row_gap + child_main > height {
+                        next_height = next_height.min(column_main + row_gap + child_main);
+                        column += 1;
+                        column_main = child_main;
+                    } else {
+                        column_main += row_gap + child_main;
+                    }
+                } else {
+                    column_main = content_size_before + child_main;
+                }
+                column_indices.push(column);
+            }
+            (column_indices, column + 1, next_height)
+        };
+
+        // Balance the columns by finding the smallest height for which the children fit within the columns, starting
+        // from the height of the tallest child. Content treated as an implicit first child is placed at the top of
+        // the first column.
+        let mut column_main = items
+            .iter()
+            .map(|child| child.main_before + child.main + child.main_after)
+            .fold(content_size_before, f32::max);
+        let column_indices = loop {
+            let (column_indices, num_used, next_height) = fill(column_main);
+            if num_used <= num_columns || next_height == f32::MAX {
+                break column_indices;
+            }
+            column_main = next_height;
+        };
+
+        // Position the children within their columns.
+        let mut max_column_main = content_size_before;
+        let mut main_pos = content_size_before;
+        for (index, child) in items.iter().enumerate() {
+            if index > 0 {
+                if column_indices[index] == column_indices[index - 1] {
+                    main_pos += row_gap;
+                } else {
+                    main_pos = 0.0;
+                }
+            }
+
+            main_pos += child.main_before;
+            cache.set_rect(
+                child.node,
+                layout_type,
+                main_pos + content_main_before - scroll_main,
+                column_indices[index] as f32 * (column_width + column_gap) + child.cross_before + content_cross_before
+                    - scroll_cross,
+                child.main,
+                child.cross,
+            );
+            set_skipped(child.node, false, ctx);
+            translate(child.node, cache, store, ctx);
+            main_pos += child.main + child.main_after;
+            max_column_main = max_column_main.max(main_pos);
+        }
+
+        // Determine the auto size of the node on the main-axis of the children from the tallest column, the content
+        // size and the self-directed children which contribute to the size of the node.
+        if indefinite_main {
+            let columns_main = max_column_main.max(content_size_main).max(contributing_main);
+            if parent_layout_type == layout_type {
+                computed_main = (columns_main + content_main_before + content_main_after).max(min_main).min(max_main);
+            } else {
+                computed_cross =
+                    (columns_main + content_main_before + content_main_after).max(min_cross).min(max_cross);
+            }
+        }
+
+        // Report the content extent of the node, covering the tallest column and the self-directed children.
+        report_content_extent(
+            node,
+            parent_layout_type,
+            layout_type,
+            Size { main: computed_main, cross: computed_cross },
+            max_column_main.max(content_main_end) + content_main_before + content_main_after,
+            content_cross_end + content_cross_before + content_cross_after,
+            cache,
+        );
+
+        let size = Size { main: computed_main, cross: computed_cross };
+        measure_size_group(node, store, parent_layout_type, auto_main, auto_cross, size, ctx);
+        return size;
+    }
+
+    // Arrange the parent-directed children of a radial node along an arc around the centre of its content box.
+    if node_layout_type == LayoutType::Radial {
+        let start_angle = node.start_angle(store).unwrap_or(0.0);
+        let angular_span = node.angular_span(store).unwrap_or(360.0);
+
+        // Determine the radius, where percentages are relative to half of the smaller side of the content box. Sides
+        // which are auto are not considered, so a percentage radius is zero if both sides are auto.
+        let radius_basis = match (indefinite_main, indefinite_cross) {
+            (false, false) => parent_main.min(parent_cross),
+            (true, false) => parent_cross,
+            (false, true) => parent_main,
+            (true, true) => 0.0,
+        };
+        let radius = node
+            .radius(store)
+            .unwrap_or(Percentage(100.0))
+            .resolve(ctx, node.font_size(store))
+            .to_px(radius_basis / 2.0, 0.0);
+
+        // Determine the angle between adjacent children. On a full circle the last child is not placed over the first.
+        let num_radial_children = num_parent_directed_children.max(1);
+        let step = if angular_span.abs() >= 360.0 {
+            angular_span / num_radial_children as f32
+        } else if num_radial_children > 1 {
+            angular_span / (num_radial_children - 1) as f32
+        } else {
+            0.0
+        };
+
+        // Lay out the parent-directed children, along with the offsets of their centres from the centre of the content
+        // box. Self-directed children which contribute to the auto size of the node are laid out in advance, while the
+        // other self-directed children are laid out once the size of the content box is known.
+        let mut radial_children = SmallVec::<[(&N, Breakpoint, Option<Size>, Option<(f32, f32)>); 32]>::new();
+        let mut index = 0;
+        for (child, breakpoint) in ordered_children.iter().copied() {
+            if child.resolved_visibility(store, &breakpoint) == Visibility::Collapsed {
+                clear_bounds(child, cache, tree, ctx);
+                set_skipped(child, true, ctx);
+                continue;
+            }
+
+            if child.position_type(store).unwrap_or_default() == PositionType::SelfDirected {
+                let contributes = child.contributes_to_size(store).unwrap_or_default();
+                let child_size = (contributes && (indefinite_main || indefinite_cross)).then(|| {
+                    layout(
+                        child,
+                        layout_type,
+                        parent_main,
+                        parent_cross,
+                        cache,
+                        tree,
+                        store,
+                        sublayout,
+                        &LayoutContext {
+                            parent_main,
+                            parent_cross,
+                            indefinite_main,
+                            indefinite_cross,
+                            breakpoint: Some(breakpoint),
+                            ..*ctx
+                        },
+                    )
+                });
+                radial_children.push((child, breakpoint, child_size, None));
+                continue;
+            }
+
+            let child_size = layout(
+                child,
+                layout_type,
+                parent_main,
+                parent_cross,
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext {
+                    parent_main,
+                    parent_cross,
+                    indefinite_main,
+                    indefinite_cross,
+                    breakpoint: Some(breakpoint),
+                    ..*ctx
+                },
+            );
+
+            let angle = (start_angle + step * index as f32).to_radians();
+            index += 1;
+            radial_children.push((
+                child,
+                breakpoint,
+                Some(child_size),
+                Some((-(radius * angle.cos()).round(), (radius * angle.sin()).round())),
+            ));
+        }
+
+        // Determine the auto size of the node from the extents of the parent-directed children around its centre, and
+        // from the space and size of the self-directed children which contribute to the size of the node.
+        let mut extent_main = 0.0f32;
+        let mut extent_cross = 0.0f32;
+        let mut contributing_main = 0.0f32;
+        let mut contributing_cross = 0.0f32;
+        for (child, _, child_size, offsets) in radial_children.iter() {
+            match (child_size, offsets) {
+                (Some(child_size), Some((offset_main, offset_cross))) => {
+                    extent_main = extent_main.max(offset_main.abs() + child_size.main / 2.0);
+                    extent_cross = extent_cross.max(offset_cross.abs() + child_size.cross / 2.0);
+                }
+                (Some(child_size), None) => {
+                    contributing_main = contributing_main.max(
+                        child.main_before(store, layout_type, ctx).to_px(parent_main, 0.0)
+                            + child_size.main
+                            + child.main_after(store, layout_type, ctx).to_px(parent_main, 0.0),
+                    );
+                    contributing_cross = contributing_cross.max(
+                        child.cross_before(store, layout_type, ctx).to_px(parent_cross, 0.0)
+                            + child_size.cross
+                            + child.cross_after(store, layout_type, ctx).to_px(parent_cross, 0.0),
+                    );
+                }
+                _ => {}
+            }
+        }
+
+        if indefinite_main {
+            let auto_main = (2.0 * extent_main).max(contributing_main) + content_main_before + content_main_after;
+            if parent_layout_type == layout_type {
+                computed_main = auto_main.max(min_main).min(max_main);
+                parent_main = (computed_main - content_main_before - content_main_after).max(0.0);
+            } else {
+                computed_cross = auto_main.max(min_cross).min(max_cross);
+                parent_main = (computed_cross - content_main_before - content_main_after).max(0.0);
+            }
+        }
+
+        if indefinite_cross {
+            let auto_cross = (2.0 * extent_cross).max(contributing_cross) + content_cross_before + content_cross_after;
+            if parent_layout_type == layout_type {
+                computed_cross = auto_cross.max(min_cross).min(max_cross);
+                parent_cross = (computed_cross - content_cross_before - content_cross_after).max(0.0);
+            } else {
+                computed_main = auto_cross.max(min_main).min(max_main);
+                parent_cross = (computed_main - content_cross_before - content_cross_after).max(0.0);
+            }
+        }
+
+        // Position the children, with self-directed children positioned by their space relative to the content box.
+        let mut content_main_end = 0.0f32;
+        let mut content_cross_end = 0.0f32;
+        for (child, breakpoint, child_size, offsets) in radial_children {
+            let (child_size, child_main_pos, child_cross_pos) = match (child_size, offsets) {
+                (Some(child_size), Some((offset_main, offset_cross))) => (
+                    child_size,
+                    parent_main / 2.0 + offset_main - child_size.main / 2.0,
+                    parent_cross / 2.0 + offset_cross - child_size.cross / 2.0,
+                ),
+                (child_size, _) => (
+                    child_size.unwrap_or_else(|| {
+                        layout(
+                            child,
+                            layout_type,
+                            parent_main,
+                            parent_cross,
+                            cache,
+                            tree,
+                            store,
+                            sublayout,
+                            &LayoutContext { parent_main, parent_cross, breakpoint: Some(breakpoint), ..*ctx },
+                        )
+                    }),
+                    child.main_before(store, layout_type, ctx).to_px(parent_main, 0.0),
+                    child.cross_before(store, layout_type, ctx).to_px(parent_cross, 0.0),
+                ),
+            };
+
+            cache.set_rect(
+                child,
+                layout_type,
+                child_main_pos + content_main_before - scroll_main,
+                child_cross_pos + content_cross_before - scroll_cross,
+                child_size.main,
+                child_size.cross,
+            );
+            set_skipped(child, false, ctx);
+            translate(child, cache, store, ctx);
+            content_main_end = content_main_end.max(child_main_pos + child_size.main);
+            content_cross_end = content_cross_end.max(child_cross_pos + child_size.cross);
+        }
+
+        // Report the content extent of the node, covering its children.
+        report_content_extent(
+            node,
+            parent_layout_type,
+            layout_type,
+            Size { main: computed_main, cross: computed_cross },
+            content_main_end + content_main_before + content_main_after,
+            content_cross_end + content_cross_before + content_cross_after,
+            cache,
+        );
+
+        let size = Size { main: computed_main, cross: computed_cross };
+        measure_size_group(node, store, parent_layout_type, auto_main, auto_cross, size, ctx);
+        return size;
+    }
+
+    // Attach the parent-directed children of a dock node to the edges of the area remaining within its content box.
+    if node_layout_type == LayoutType::Dock {
+        // The space between a child docked to the top or bottom edge and the remaining area, and between a child docked
+        // to the left or right edge and the remaining area.
+        let row_gap = node.main_between(store, layout_type, ctx).to_px(parent_main, 0.0);
+        let column_gap = node.cross_between(store, layout_type, ctx).to_px(parent_cross, 0.0);
+
+        // The size of each self-directed child which contributes to the auto size of the node, laid out in advance.
+        let mut contributing_sizes = SmallVec::<[Option<Size>; 32]>::from_elem(None, ordered_children.len());
+
+        // Determine the auto size of the node by laying out the parent-directed children in advance, adding the size of
+        // each child docked to an edge, and the space after it, to the size needed by the children after it, down to
+        // the last child which fills the remaining area. The auto size also covers the space and size of the
+        // self-directed children which contribute to the size of the node.
+        if indefinite_main || indefinite_cross {
+            let mut contributing_main = 0.0f32;
+            let mut contributing_cross = 0.0f32;
+            for (index, (child, breakpoint)) in ordered_children.iter().copied().enumerate() {
+                if child.resolved_visibility(store, &breakpoint) == Visibility::Collapsed
+                    || child.position_type(store).unwrap_or_default() != PositionType::SelfDirected
+                    || !child.contributes_to_size(store).unwrap_or_default()
+                {
+                    continue;
+                }
+
+                let child_size = layout(
+                    child,
+                    layout_type,
+                    parent_main,
+                    parent_cross,
+                    cache,
+                    tree,
+                    store,
+                    sublayout,
+                    &LayoutContext {
+                        parent_main,
+                        parent_cross,
+                        indefinite_main,
+                        indefinite_cross,
+                        breakpoint: Some(breakpoint),
+                        ..*ctx
+                    },
+                );
+
+                contributing_main = contributing_main.max(
+                    child.main_before(store, layout_type, ctx).to_px(parent_main, 0.0)
+                        + child_size.main
+                        + child.main_after(store, layout_type, ctx).to_px(parent_main, 0.0),
+                );
+                contributing_cross = contributing_cross.max(
+                    child.cross_before(store, layout_type, ctx).to_px(parent_cross, 0.0)
+                        + child_size.cross
+                        + child.cross_after(store, layout_type, ctx).to_px(parent_cross, 0.0),
+                );
+                contributing_sizes[index] = Some(child_size);
+            }
+
+            let docked_children = ordered_children
+                .iter()
+                .copied()
+                .filter(|(child, breakpoint)| child.resolved_visibility(store, breakpoint) != Visibility::Collapsed)
+                .filter(|(child, _)| child.position_type(store).unwrap_or_default() != PositionType::SelfDirected)
+                .map(|(child, breakpoint)| {
+                    let child_size = layout(
+                        child,
+                        layout_type,
+                        parent_main,
+                        parent_cross,
+                        cache,
+                        tree,
+                        store,
+                        sublayout,
+                        &LayoutContext {
+                            parent_main,
+                            parent_cross,
+                            indefinite_main,
+                            indefinite_cross,
+                            breakpoint: Some(breakpoint),
+                            ..*ctx
+                        },
+                    );
+                    (child.dock(store).unwrap_or_default(), child_size)
+                })
+                .collect::<SmallVec<[(Dock, Size); 32]>>();
+
+            let (needed_main, needed_cross) = docked_children.iter().rev().enumerate().fold(
+                (0.0f32, 0.0f32),
+                |(needed_main, needed_cross), (index, (dock, child_size))| match dock {
+                    _ if index == 0 => (child_size.main, child_size.cross),
+                    Dock::Top | Dock::Bottom => {
+                        (needed_main + child_size.main + row_gap, needed_cross.max(child_size.cross))
+                    }
+                    Dock::Left | Dock::Right => {
+                        (needed_main.max(child_size.main), needed_cross + child_size.cross + column_gap)
+                    }
+                },
+            );
+
+            if indefinite_main {
+                let auto_main = needed_main.max(contributing_main) + content_main_before + content_main_after;
+                if parent_layout_type == layout_type {
+                    computed_main = auto_main.max(min_main).min(max_main);
+                    parent_main = (computed_main - content_main_before - content_main_after).max(0.0);
+                } else {
+                    computed_cross = auto_main.max(min_cross).min(max_cross);
+                    parent_main = (computed_cross - content_main_before - content_main_after).max(0.0);
+                }
+            }
+
+            if indefinite_cross {
+                let auto_cross = needed_cross.max(contributing_cross) + content_cross_before + content_cross_after;
+                if parent_layout_type == layout_type {
+                    computed_cross = auto_cross.max(min_cross).min(max_cross);
+                    parent_cross = (computed_cross - content_cross_before - content_cross_after).max(0.0);
+                } else {
+                    computed_main = auto_cross.max(min_main).min(max_main);
+                    parent_cross = (computed_main - content_cross_before - content_cross_after).max(0.0);
+                }
+            }
+        }
+
+        // The remaining area, on the axes of the children, which are vertical on the main-axis.
+        let mut remaining_main_pos = 0.0;
+        let mut remaining_cross_pos = 0.0;
+        let mut remaining_main = parent_main;
+        let mut remaining_cross = parent_cross;
+
+        let mut index = 0;
+        let mut content_main_end = 0.0f32;
+        let mut content_cross_end = 0.0f32;
+        for (child_index, (child, breakpoint)) in ordered_children.iter().copied().enumerate() {
+            if child.resolved_visibility(store, &breakpoint) == Visibility::Collapsed {
+                clear_bounds(child, cache, tree, ctx);
+                set_skipped(child, true, ctx);
+                continue;
+            }
+
+            // Self-directed children are positioned by their space relative to the content box.
+            if child.position_type(store).unwrap_or_default() == PositionType::SelfDirected {
+                let child_size = contributing_sizes[child_index].unwrap_or_else(|| {
+                    layout(
+                        child,
+                        layout_type,
+                        parent_main,
+                        parent_cross,
+                        cache,
+                        tree,
+                        store,
+                        sublayout,
+                        &LayoutContext { parent_main, parent_cross, breakpoint: Some(breakpoint), ..*ctx },
+                    )
+                });
+
+                let child_main_pos = child.main_before(store, layout_type, ctx).to_px(parent_main, 0.0);
+                let child_cross_pos = child.cross_before(store, layout_type, ctx).to_px(parent_cross, 0.0);
+                cache.set_rect(
+                    child,
+                    layout_type,
+                    child_main_pos + content_main_before - scroll_main,
+                    child_cross_pos + content_cross_before - scroll_cross,
+                    child_size.main,
+                    child_size.cross,
+                );
+                set_skipped(child, false, ctx);
+                translate(child, cache, store, ctx);
+                content_main_end = content_main_end.max(child_main_pos + child_size.main);
+                content_cross_end = content_cross_end.max(child_cross_pos + child_size.cross);
+                continue;
+            }
+
+            let child_size = layout(
+                child,
+                layout_type,
+                remaining_main,
+                remaining_cross,
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext {
+                    parent_main: remaining_main,
+                    parent_cross: remaining_cross,
+                    breakpoint: Some(breakpoint),
+                    ..*ctx
+                },
+            );
+
+            // Carve the space of the child, and the space after it, from the remaining area, unless it is the last child
+            // which fills it.
+            let (child_main_pos, child_cross_pos) = if index + 1 == num_parent_directed_children {
+                (remaining_main_pos, remaining_cross_pos)
+            } else {
+                match child.dock(store).unwrap_or_default() {
+                    Dock::Top => {
+                        let pos = (remaining_main_pos, remaining_cross_pos);
+                        remaining_main_pos += child_size.main + row_gap;
+                        remaining_main = (remaining_main - child_size.main - row_gap).max(0.0);
+                        pos
+                    }
+                    Dock::Bottom => {
+                        remaining_main = (remaining_main - child_size.main - row_gap).max(0.0);
+                        (remaining_main_pos + remaining_main + row_gap, remaining_cross_pos)
+                    }
+                    Dock::Left => {
+                        let pos = (remaining_main_pos, remaining_cross_pos);
+                        remaining_cross_pos += child_size.cross + column_gap;
+                        remaining_cross = (remaining_cross - child_size.cross - column_gap).max(0.0);
+                        pos
+                    }
+                    Dock::Right => {
+                        remaining_cross = (remaining_cross - child_size.cross - column_gap).max(0.0);
+                        (remaining_main_pos, remaining_cross_pos + remaining_cross + column_gap)
+                    }
+                }
+            };
+            index += 1;
+
+            cache.set_rect(
+                child,
+                layout_type,
+                child_main_pos + content_main_before - scroll_main,
+                child_cross_pos + content_cross_before - scroll_cross,
+                child_size.main,
+                child_size.cross,
+            );
+            set_skipped(child, false, ctx);
+            translate(child, cache, store, ctx);
+            content_main_end = content_main_end.max(child_main_pos + child_size.main);
+            content_cross_end = content_cross_end.max(child_cross_pos + child_size.cross);
+        }
+
+        // Report the content extent of the node, covering its children.
+        report_content_extent(
+            node,
+            parent_layout_type,
+            layout_type,
+            Size { main: computed_main, cross: computed_cross },
+            content_main_end + content_main_before + content_main_after,
+            content_cross_end + content_cross_before + content_cross_after,
+            cache,
+        );
+
+        let size = Size { main: computed_main, cross: computed_cross };
+        measure_size_group(node, store, parent_layout_type, auto_main, auto_cross, size, ctx);
+        return size;
+    }
+
+    // The content contributes to the space and size of the children.
+    main_sum += content_size_before;
+    cross_max = cross_max.max(content_size_cross);
 
     // Sum of all space and size flex factors on the main-axis of the node.
     let mut main_flex_sum = 0.0;
@@ -220,79 +1253,264 @@ where
     let mut main_axis = SmallVec::<[StretchItem; 32]>::new();
 
     // Parent overrides for child auto space.
-    let node_child_main_before = node.child_main_before(store, layout_type);
-    let node_child_main_after = node.child_main_after(store, layout_type);
-    let node_child_cross_before = node.child_cross_before(store, layout_type);
-    let node_child_cross_after = node.child_cross_after(store, layout_type);
-    let node_child_main_between = node.main_between(store, layout_type);
+    let node_child_main_before = node.child_main_before(store, layout_type, ctx);
+    let node_child_main_after = node.child_main_after(store, layout_type, ctx);
+    let node_child_cross_before = node.child_cross_before(store, layout_type, ctx);
+    let node_child_cross_after = node.child_cross_after(store, layout_type, ctx);
+    let node_child_main_between = node.main_between(store, layout_type, ctx);
+
+    // Parent constraints for the overridden child space.
+    let node_min_child_main_before = node.min_child_main_before(store, layout_type, ctx);
+    let node_max_child_main_before = node.max_child_main_before(store, layout_type, ctx);
+    let node_min_child_main_after = node.min_child_main_after(store, layout_type, ctx);
+    let node_max_child_main_after = node.max_child_main_after(store, layout_type, ctx);
+    let node_min_child_cross_before = node.min_child_cross_before(store, layout_type, ctx);
+    let node_max_child_cross_before = node.max_child_cross_before(store, layout_type, ctx);
+    let node_min_child_cross_after = node.min_child_cross_after(store, layout_type, ctx);
+    let node_max_child_cross_after = node.max_child_cross_after(store, layout_type, ctx);
+    let node_min_child_main_between = node.min_main_between(store, layout_type, ctx);
+    let node_max_child_main_between = node.max_main_between(store, layout_type, ctx);
+
+    // Apply the main-axis alignment of the node to the parent overrides.
+    let (node_child_main_before, node_child_main_between, node_child_main_after) = match node.main_alignment(store) {
+        Some(alignment) => alignment.apply(node_child_main_before, node_child_main_between, node_child_main_after),
+        None => (node_child_main_before, node_child_main_between, node_child_main_after),
+    };
+
+    // Returns the parent overrides for the auto cross-axis space of a child, determined by the cross-axis
+    // alignment of the node or the `align_self` of the child.
+    let child_cross_space = |child: &N| match child.align_self(store).or(node.cross_alignment(store)) {
+        Some(alignment) => alignment.apply(node_child_cross_before, node_child_cross_after),
+        None => (node_child_cross_before, node_child_cross_after),
+    };
+
+    // The parent-directed children in the order they are arranged.
+    let parent_directed_children = ordered_children
+        .iter()
+        .copied()
+        .filter(|(child, breakpoint)| child.resolved_visibility(store, breakpoint) != Visibility::Collapsed)
+        .filter(|(child, _)| child.position_type(store).unwrap_or_default() != PositionType::SelfDirected)
+        .collect::<SmallVec<[(&N, Breakpoint); 32]>>();
+
+    // Determine which parent-directed children are collapsed because they do not fit within the node on the main-axis.
+    // Children are collapsed in order of lowest overflow priority, and from last to first for equal priorities.
+    let mut overflowed = SmallVec::<[bool; 32]>::from_elem(false, num_parent_directed_children);
+
+    // The size of each parent-directed child which is laid out to determine whether it overflows, along with the space
+    // of the node it was laid out in. Children are not laid out again within the same space.
+    let mut measured = SmallVec::<[Option<Size>; 32]>::from_elem(None, num_parent_directed_children);
+    let measured_space = (parent_main, parent_cross);
+
+    if overflow == Overflow::Collapse {
+        // The space available to the children. If the size of the node is auto this is limited by its max size.
+        let available = if indefinite_main {
+            let max = if parent_layout_type == layout_type { max_main } else { max_cross };
+            max - content_main_before - content_main_after - content_size_before
+        } else {
+            parent_main - content_size_before
+        };
+
+        // The fixed-size space, size, and overflow priority of each child on the main-axis, with stretch space and
+        // size at their minimum. Auto space depends on which children remain so is resolved below.
+        let mut extents = SmallVec::<[(Option<f32>, f32, Option<f32>, Option<i32>); 32]>::new();
+
+        for (index, (child, breakpoint)) in parent_directed_children.iter().copied().enumerate() {
+            let child_main_before = child.main_before(store, layout_type, ctx);
+            let child_main = child.main(store, layout_type, ctx, &breakpoint);
+            let child_main_after = child.main_after(store, layout_type, ctx);
+
+            let computed_child_main_before = (!child_main_before.is_auto()).then(|| {
+                child_main_before.to_px_clamped(
+                    parent_main,
+                    0.0,
+                    child.min_main_before(store, layout_type, ctx),
+                    child.max_main_before(store, layout_type, ctx),
+                )
+            });
+
+            let computed_child_main_after = (!child_main_after.is_auto()).then(|| {
+                child_main_after.to_px_clamped(
+                    parent_main,
+                    0.0,
+                    child.min_main_after(store, layout_type, ctx),
+                    child.max_main_after(store, layout_type, ctx),
+                )
+            });
+
+            let computed_child_main = if child_main.is_stretch() {
+                child.min_main(store, layout_type, ctx).to_px(parent_main, 0.0)
+            } else {
+                let child_size = layout(
+                    child,
+                    layout_type,
+                    parent_main,
+                    parent_cross,
+                    cache,
+                    tree,
+                    store,
+                    sublayout,
+                    &LayoutContext {
+                        parent_main,
+                        parent_cross,
+                        indefinite_main,
+                        indefinite_cross,
+                        breakpoint: Some(breakpoint),
+                        ..*ctx
+                    },
+                );
+                measured[index] = Some(child_size);
+                child_size.main
+            };
+
+            extents.push((
+                computed_child_main_before,
+                computed_child_main,
+                computed_child_main_after,
+                child.overflow_priority(store),
+            ));
+        }
+
+        // The parent overrides for auto child space.
+        let computed_node_child_main_before = node_child_main_before.to_px_clamped(
+            parent_main,
+            0.0,
+            node_min_child_main_before,
+            node_max_child_main_before,
+        );
+        let computed_node_child_main_after =
+            node_child_main_after.to_px_clamped(parent_main, 0.0, node_min_child_main_after, node_max_child_main_after);
+        let computed_node_child_main_between = node_child_main_between.to_px_clamped(
+            parent_main,
+            0.0,
+            node_min_child_main_between,
+            node_max_child_main_between,
+        );
+
+        // Returns the total space and size of the children which are not collapsed.
+        let total = |overflowed: &[bool]| {
+            let mut remaining = extents
+                .iter()
+                .zip(overflowed)
+                .filter(|(_, overflowed)| !**overflowed)
+                .map(|(extent, _)| extent)
+                .peekable();
+            let mut total = 0.0;
+            let mut is_first = true;
+            while let Some((main_before, main, main_after, _)) = remaining.next() {
+                total += main_before.unwrap_or(if is_first { computed_node_child_main_before } else { 0.0 });
+                total += main;
+                total += main_after.unwrap_or(match remaining.peek() {
+                    None => computed_node_child_main_after,
+                    Some((None, ..)) => computed_node_child_main_between,
+                    Some(_) => 0.0,
+                });
+                is_first = false;
+            }
+            total
+        };
+
+        while total(&overflowed) > available {
+            let lowest = extents
+                .iter()
+                .enumerate()
+                .filter(|(index, _)| !overflowed[*index])
+                .filter_map(|(index, extent)| extent.3.map(|priority| (index, priority)))
+                .min_by_key(|(index, priority)| (*priority, std::cmp::Reverse(*index)));
+
+            match lowest {
+                Some((index, _)) => overflowed[index] = true,
+                None => break,
+            }
+        }
+    }
+
+    // Overflowed children are skipped by layout along with their children.
+    let num_parent_directed_children =
+        num_parent_directed_children - overflowed.iter().filter(|overflowed| **overflowed).count();
 
     // Determine index of first and last parent-directed child nodes.
-    let mut iter = node
-        .children(tree)
-        .filter(|child| child.visible(store))
-        .filter(|child| child.position_type(store).unwrap_or_default() == PositionType::ParentDirected)
+    let mut iter = parent_directed_children
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(index, _)| !overflowed[*index])
+        .map(|(_, child)| child)
         .enumerate();
 
     let first = iter.next().map(|(index, _)| index);
     let last = iter.last().map_or(first, |(index, _)| Some(index));
 
-    let mut node_children = node
-        .children(tree)
-        .filter(|child| child.visible(store))
-        .filter(|child| child.position_type(store).unwrap_or_default() == PositionType::ParentDirected)
+    let mut node_children = parent_directed_children
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(index, _)| !overflowed[*index])
+        .map(|(index, (child, breakpoint))| (child, breakpoint, measured[index]))
         .enumerate()
         .peekable();
 
     // Compute space and size of non-flexible parent-directed children.
-    while let Some((index, child)) = node_children.next() {
+    while let Some((index, (child, breakpoint, child_measured))) = node_children.next() {
         // Get desired space and size.
-        let mut child_main_before = child.main_before(store, layout_type);
-        let child_main = child.main(store, layout_type);
-        let mut child_main_after = child.main_after(store, layout_type);
+        let mut child_main_before = child.main_before(store, layout_type, ctx);
+        let child_main = child.main(store, layout_type, ctx, &breakpoint);
+        let mut child_main_after = child.main_after(store, layout_type, ctx);
 
-        let mut child_cross_before = child.cross_before(store, layout_type);
-        let child_cross = child.cross(store, layout_type);
-        let mut child_cross_after = child.cross_after(store, layout_type);
+        let mut child_cross_before = child.cross_before(store, layout_type, ctx);
+        let child_cross = child.cross(store, layout_type, ctx, &breakpoint);
+        let mut child_cross_after = child.cross_after(store, layout_type, ctx);
 
         // Get fixed-size space and size constraints.
-        let child_min_cross_before = child.min_cross_before(store, layout_type);
-        let child_max_cross_before = child.max_cross_before(store, layout_type);
+        let mut child_min_cross_before = child.min_cross_before(store, layout_type, ctx);
+        let mut child_max_cross_before = child.max_cross_before(store, layout_type, ctx);
 
-        let child_min_cross_after = child.min_cross_after(store, layout_type);
-        let child_max_cross_after = child.max_cross_after(store, layout_type);
+        let mut child_min_cross_after = child.min_cross_after(store, layout_type, ctx);
+        let mut child_max_cross_after = child.max_cross_after(store, layout_type, ctx);
 
-        let child_min_main_before = child.min_main_before(store, layout_type);
-        let child_max_main_before = child.max_main_before(store, layout_type);
+        let mut child_min_main_before = child.min_main_before(store, layout_type, ctx);
+        let mut child_max_main_before = child.max_main_before(store, layout_type, ctx);
 
-        let child_min_main_after = child.min_main_after(store, layout_type);
-        let child_max_main_after = child.max_main_after(store, layout_type);
+        let mut child_min_main_after = child.min_main_after(store, layout_type, ctx);
+        let mut child_max_main_after = child.max_main_after(store, layout_type, ctx);
 
-        let child_min_main = child.min_main(store, layout_type);
-        let child_max_main = child.max_main(store, layout_type);
+        let child_min_main = child.min_main(store, layout_type, ctx);
+        let child_max_main = child.max_main(store, layout_type, ctx);
 
         // Apply parent child_space overrides to auto child space.
         if child_main_before.is_auto() && first == Some(index) {
             child_main_before = node_child_main_before;
+            child_min_main_before = node_min_child_main_before;
+            child_max_main_before = node_max_child_main_before;
         }
 
         if child_main_after.is_auto() {
             if last == Some(index) {
                 child_main_after = node_child_main_after;
-            } else if let Some((_, next_node)) = node_children.peek() {
+                child_min_main_after = node_min_child_main_after;
+                child_max_main_after = node_max_child_main_after;
+            } else if let Some((_, (next_node, ..))) = node_children.peek() {
                 // Only apply main between if both adjacent children have auto space between
-                let next_main_before = next_node.main_before(store, layout_type);
+                let next_main_before = next_node.main_before(store, layout_type, ctx);
                 if next_main_before.is_auto() {
                     child_main_after = node_child_main_between;
+                    child_min_main_after = node_min_child_main_between;
+                    child_max_main_after = node_max_child_main_between;
                 }
             }
         }
 
+        let (node_child_cross_before, node_child_cross_after) = child_cross_space(child);
+
         if child_cross_before.is_auto() {
             child_cross_before = node_child_cross_before;
+            child_min_cross_before = node_min_child_cross_before;
+            child_max_cross_before = node_max_child_cross_before;
         }
 
         if child_cross_after.is_auto() {
             child_cross_after = node_child_cross_after;
+            child_min_cross_after = node_min_child_cross_after;
+            child_max_cross_after = node_max_child_cross_after;
         }
 
         // Collect stretch main items.
@@ -339,8 +1557,8 @@ where
 
         let mut computed_child_cross = child_cross.to_px(parent_cross, 0.0);
 
-        if child.min_cross(store, layout_type).is_auto() {
-            let p_cross = if child.min_cross(store, layout_type).is_auto() { None } else { Some(parent_cross) };
+        if child.min_cross(store, layout_type, ctx).is_auto() {
+            let p_cross = if child.min_cross(store, layout_type, ctx).is_auto() { None } else { Some(parent_cross) };
 
             if let Some(content_size) = child.content_sizing(store, sublayout, layout_type, p_cross, p_cross) {
                 // min_main = content_size.0;
@@ -348,20 +1566,45 @@ where
             }
         }
 
-        // Compute fixed-size child main_before.
-        let computed_child_main_before =
-            child_main_before.to_px_clamped(parent_main, 0.0, child_min_main_before, child_max_main_before);
+        // Compute fixed-size child main_before. Stretch space is constrained when it is computed below.
+        let computed_child_main_before = if child_main_before.is_stretch() {
+            0.0
+        } else {
+            child_main_before.to_px_clamped(parent_main, 0.0, child_min_main_before, child_max_main_before)
+        };
 
-        // Compute fixed-size child main_after.
-        let computed_child_main_after =
-            child_main_after.to_px_clamped(parent_main, 0.0, child_min_main_after, child_max_main_after);
+        // Compute fixed-size child main_after. Stretch space is constrained when it is computed below.
+        let computed_child_main_after = if child_main_after.is_stretch() {
+            0.0
+        } else {
+            child_main_after.to_px_clamped(parent_main, 0.0, child_min_main_after, child_max_main_after)
+        };
 
         let mut computed_child_main = 0.0;
         // let mut computed_child_cross = 0.0;
 
-        // Compute fixed-size child main and cross.
+        // Compute fixed-size child main and cross. Children which have been measured are not laid out again.
         if !child_main.is_stretch() && !child_cross.is_stretch() {
-            let child_size = layout(child, layout_type, parent_main, parent_cross, cache, tree, store, sublayout);
+            let child_size = child_measured.unwrap_or_else(|| {
+                layout(
+                    child,
+                    layout_type,
+                    parent_main,
+                    parent_cross,
+                    cache,
+                    tree,
+                    store,
+                    sublayout,
+                    &LayoutContext {
+                        parent_main,
+                        parent_cross,
+                        indefinite_main,
+                        indefinite_cross,
+                        breakpoint: Some(breakpoint),
+                        ..*ctx
+                    },
+                )
+            });
 
             computed_child_main = child_size.main;
             computed_child_cross = child_size.cross;
@@ -372,33 +1615,39 @@ where
 
         children.push(ChildNode {
             node: child,
+            breakpoint,
             cross_before: computed_child_cross_before,
             cross: computed_child_cross,
             cross_after: computed_child_cross_after,
             main_before: computed_child_main_before,
             main: computed_child_main,
             main_after: computed_child_main_after,
+            measured: child_measured,
         });
     }
 
     // Determine auto main and cross size from space and size of children.
     if num_parent_directed_children != 0 {
-        if main.is_auto() || node.min_main(store, parent_layout_type).is_auto() {
+        if main.is_auto() || node.min_main(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_main = main_sum + border_main_before + border_main_after;
-                parent_main = parent_main.max(min_main).min(max_main);
+                min_main = main_sum.max(content_size_main) + inset_main_before + inset_main_after;
+                parent_main = parent_main
+                    .max(main_sum.max(content_size_main))
+                    .min(max_main - inset_main_before - inset_main_after);
             } else {
-                min_main = cross_max + border_main_before + border_main_after;
-                parent_cross = parent_cross.max(min_main).min(max_main);
+                min_main = cross_max + inset_main_before + inset_main_after;
+                parent_cross = parent_cross.max(cross_max).min(max_main - inset_main_before - inset_main_after);
             }
         }
-        if cross.is_auto() || node.min_cross(store, parent_layout_type).is_auto() {
+        if cross.is_auto() || node.min_cross(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_cross = cross_max + border_cross_before + border_cross_after;
-                parent_cross = parent_cross.max(min_cross).min(max_cross);
+                min_cross = cross_max + inset_cross_before + inset_cross_after;
+                parent_cross = parent_cross.max(cross_max).min(max_cross - inset_cross_before - inset_cross_after);
             } else {
-                min_cross = main_sum + border_cross_before + border_cross_after;
-                parent_main = parent_main.max(min_cross).min(max_cross);
+                min_cross = main_sum.max(content_size_main) + inset_cross_before + inset_cross_after;
+                parent_main = parent_main
+                    .max(main_sum.max(content_size_main))
+                    .min(max_cross - inset_cross_before - inset_cross_after);
             }
         }
     }
@@ -409,21 +1658,33 @@ where
     // Compute flexible space and size on the cross-axis for parent-directed children.
     for (index, child) in children
         .iter_mut()
-        .filter(|child| child.node.position_type(store).unwrap_or_default() == PositionType::ParentDirected)
-        .filter(|child| !child.node.cross(store, layout_type).is_auto())
+        .filter(|child| child.node.position_type(store).unwrap_or_default() != PositionType::SelfDirected)
+        .filter(|child| !child.node.cross(store, layout_type, ctx, &child.breakpoint).is_auto())
         .enumerate()
     {
-        let mut child_cross_before = child.node.cross_before(store, layout_type);
-        let child_cross = child.node.cross(store, layout_type);
-        let mut child_cross_after = child.node.cross_after(store, layout_type);
+        let mut child_cross_before = child.node.cross_before(store, layout_type, ctx);
+        let child_cross = child.node.cross(store, layout_type, ctx, &child.breakpoint);
+        let mut child_cross_after = child.node.cross_after(store, layout_type, ctx);
+
+        // Get space constraints.
+        let mut child_min_cross_before = child.node.min_cross_before(store, layout_type, ctx);
+        let mut child_max_cross_before = child.node.max_cross_before(store, layout_type, ctx);
+        let mut child_min_cross_after = child.node.min_cross_after(store, layout_type, ctx);
+        let mut child_max_cross_after = child.node.max_cross_after(store, layout_type, ctx);
 
         // Apply child_space overrides.
+        let (node_child_cross_before, node_child_cross_after) = child_cross_space(child.node);
+
         if child_cross_before.is_auto() {
             child_cross_before = node_child_cross_before;
+            child_min_cross_before = node_min_child_cross_before;
+            child_max_cross_before = node_max_child_cross_before;
         }
 
         if child_cross_after.is_auto() {
             child_cross_after = node_child_cross_after;
+            child_min_cross_after = node_min_child_cross_after;
+            child_max_cross_after = node_max_child_cross_after;
         }
 
         let mut cross_flex_sum = 0.0;
@@ -431,10 +1692,8 @@ where
         // Collect stretch cross items.
         let mut cross_axis = SmallVec::<[StretchItem; 3]>::new();
         if let Stretch(factor) = child_cross_before {
-            let child_min_cross_before =
-                child.node.min_cross_before(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross_before =
-                child.node.max_cross_before(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross_before = child_min_cross_before.to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross_before = child_max_cross_before.to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -450,8 +1709,8 @@ where
         }
 
         if let Stretch(factor) = child_cross {
-            let child_min_cross = child.node.min_cross(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross = child.node.max_cross(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross = child.node.min_cross(store, layout_type, ctx).to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross = child.node.max_cross(store, layout_type, ctx).to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -461,8 +1720,8 @@ where
         }
 
         if let Stretch(factor) = child_cross_after {
-            let child_min_cross_after = child.node.min_cross_after(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross_after = child.node.max_cross_after(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross_after = child_min_cross_after.to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross_after = child_max_cross_after.to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -484,12 +1743,7 @@ where
             }
 
             // Compute free space in the cross axis.
-            let child_cross_free_space = parent_cross
-                - border_cross_before
-                - border_cross_after
-                - child.cross_before
-                - child.cross
-                - child.cross_after;
+            let child_cross_free_space = parent_cross - child.cross_before - child.cross - child.cross_after;
 
             // Total size violation in the cross axis.
             let mut total_violation = 0.0;
@@ -519,17 +1773,34 @@ where
                     match item.item_type {
                         ItemType::Size => {
                             child.cross = item.computed;
-                            if !child.node.main(store, layout_type).is_stretch() {
-                                let child_size = layout(
-                                    child.node,
-                                    layout_type,
-                                    parent_main,
-                                    item.computed,
-                                    cache,
-                                    tree,
-                                    store,
-                                    sublayout,
-                                );
+                            if !child.node.main(store, layout_type, ctx, &child.breakpoint).is_stretch() {
+                                // Children which have been measured at this size are not laid out again.
+                                let child_size = match child.measured {
+                                    Some(size)
+                                        if size.cross == item.computed
+                                            && (parent_main, parent_cross) == measured_space =>
+                                    {
+                                        size
+                                    }
+                                    _ => layout(
+                                        child.node,
+                                        layout_type,
+                                        parent_main,
+                                        item.computed,
+                                        cache,
+                                        tree,
+                                        store,
+                                        sublayout,
+                                        &LayoutContext {
+                                            parent_main,
+                                            parent_cross,
+                                            indefinite_main,
+                                            indefinite_cross,
+                                            breakpoint: Some(child.breakpoint),
+                                            ..*ctx
+                                        },
+                                    ),
+                                };
                                 child.main = child_size.main;
                                 child.cross = child_size.cross;
 
@@ -552,24 +1823,83 @@ where
         cross_max = cross_max.max(child.cross_before + child.cross + child.cross_after);
     }
 
+    // Give each non-flexible parent-directed child the largest main size, and optionally cross size, of the children.
+    let uniform_children = node.uniform_children(store).unwrap_or_default();
+    if uniform_children != UniformChildren::Disabled {
+        let uniform_main = children
+            .iter()
+            .filter(|child| !child.node.main(store, layout_type, ctx, &child.breakpoint).is_stretch())
+            .fold(0.0f32, |max, child| max.max(child.main));
+        let uniform_cross = (uniform_children == UniformChildren::MainAndCross).then(|| {
+            children
+                .iter()
+                .filter(|child| !child.node.cross(store, layout_type, ctx, &child.breakpoint).is_stretch())
+                .fold(0.0f32, |max, child| max.max(child.cross))
+        });
+
+        for child in children.iter_mut() {
+            if child.node.main(store, layout_type, ctx, &child.breakpoint).is_stretch() {
+                continue;
+            }
+
+            let child_cross_stretch = child.node.cross(store, layout_type, ctx, &child.breakpoint).is_stretch();
+            let child_uniform_cross = if child_cross_stretch { None } else { uniform_cross };
+
+            // Children which already have the uniform size are not laid out again.
+            if child.main == uniform_main && child.cross == child_uniform_cross.unwrap_or(child.cross) {
+                continue;
+            }
+
+            let child_size = layout(
+                child.node,
+                layout_type,
+                parent_main,
+                if child_cross_stretch { child.cross } else { parent_cross },
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext {
+                    parent_main,
+                    parent_cross,
+                    indefinite_main,
+                    indefinite_cross,
+                    uniform_main: Some(uniform_main),
+                    uniform_cross: child_uniform_cross,
+                    breakpoint: Some(child.breakpoint),
+                    ..*ctx
+                },
+            );
+
+            main_sum += child_size.main - child.main;
+            child.main = child_size.main;
+            child.cross = child_size.cross;
+            cross_max = cross_max.max(child.cross_before + child.cross + child.cross_after);
+        }
+    }
+
     // Determine auto main and cross size from space and size of children.
     if num_parent_directed_children != 0 {
-        if main.is_auto() || node.min_main(store, parent_layout_type).is_auto() {
+        if main.is_auto() || node.min_main(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_main = main_sum + border_main_before + border_main_after;
-                parent_main = parent_main.max(min_main).min(max_main);
+                min_main = main_sum.max(content_size_main) + inset_main_before + inset_main_after;
+                parent_main = parent_main
+                    .max(main_sum.max(content_size_main))
+                    .min(max_main - inset_main_before - inset_main_after);
             } else {
-                min_main = cross_max + border_main_before + border_main_after;
-                parent_cross = parent_cross.max(min_main).min(max_main);
+                min_main = cross_max + inset_main_before + inset_main_after;
+                parent_cross = parent_cross.max(cross_max).min(max_main - inset_main_before - inset_main_after);
             }
         }
-        if cross.is_auto() || node.min_cross(store, parent_layout_type).is_auto() {
+        if cross.is_auto() || node.min_cross(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_cross = cross_max + border_cross_before + border_cross_after;
-                parent_cross = parent_cross.max(min_cross).min(max_cross);
+                min_cross = cross_max + inset_cross_before + inset_cross_after;
+                parent_cross = parent_cross.max(cross_max).min(max_cross - inset_cross_before - inset_cross_after);
             } else {
-                min_cross = main_sum + border_cross_before + border_cross_after;
-                parent_main = parent_main.max(min_cross).min(max_cross);
+                min_cross = main_sum.max(content_size_main) + inset_cross_before + inset_cross_after;
+                parent_main = parent_main
+                    .max(main_sum.max(content_size_main))
+                    .min(max_cross - inset_cross_before - inset_cross_after);
             }
         }
     }
@@ -585,8 +1915,10 @@ where
                 break;
             }
 
-            // Calculate free space on the main-axis.
-            let free_main_space = parent_main - main_sum - border_main_before - border_main_after;
+            // Calculate free space on the main-axis. The main-axis of a scrolling node is unconstrained, so its children
+            // share the free space of the visible region but are not shrunk when they overflow it.
+            let free_main_space =
+                if overflow == Overflow::Scroll { (parent_main - main_sum).max(0.0) } else { parent_main - main_sum };
 
             let mut total_violation = 0.0;
 
@@ -599,15 +1931,27 @@ where
                         child.node,
                         layout_type,
                         actual_main,
-                        if child.node.cross(store, layout_type).is_stretch() { child.cross } else { parent_cross },
+                        if child.node.cross(store, layout_type, ctx, &child.breakpoint).is_stretch() {
+                            child.cross
+                        } else {
+                            parent_cross
+                        },
                         cache,
                         tree,
                         store,
                         sublayout,
+                        &LayoutContext {
+                            parent_main,
+                            parent_cross,
+                            indefinite_main,
+                            indefinite_cross,
+                            breakpoint: Some(child.breakpoint),
+                            ..*ctx
+                        },
                     );
                     child.cross = child_size.cross;
                     cross_max = cross_max.max(child.cross_before + child.cross + child.cross_after);
-                    if child.node.min_main(store, layout_type).is_auto() {
+                    if child.node.min_main(store, layout_type, ctx).is_auto() {
                         item.min = child_size.main;
                     }
                 }
@@ -653,22 +1997,26 @@ where
 
     // Determine auto main and cross size from space and size of children.
     if num_parent_directed_children != 0 {
-        if main.is_auto() || node.min_main(store, parent_layout_type).is_auto() {
+        if main.is_auto() || node.min_main(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_main = main_sum + border_main_before + border_main_after;
-                parent_main = parent_main.max(min_main).min(max_main);
+                min_main = main_sum.max(content_size_main) + inset_main_before + inset_main_after;
+                parent_main = parent_main
+                    .max(main_sum.max(content_size_main))
+                    .min(max_main - inset_main_before - inset_main_after);
             } else {
-                min_main = cross_max + border_main_before + border_main_after;
-                parent_cross = parent_cross.max(min_main).min(max_main);
+                min_main = cross_max + inset_main_before + inset_main_after;
+                parent_cross = parent_cross.max(cross_max).min(max_main - inset_main_before - inset_main_after);
             }
         }
-        if cross.is_auto() || node.min_cross(store, parent_layout_type).is_auto() {
+        if cross.is_auto() || node.min_cross(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_cross = cross_max + border_cross_before + border_cross_after;
-                parent_cross = parent_cross.max(min_cross).min(max_cross);
+                min_cross = cross_max + inset_cross_before + inset_cross_after;
+                parent_cross = parent_cross.max(cross_max).min(max_cross - inset_cross_before - inset_cross_after);
             } else {
-                min_cross = main_sum + border_cross_before + border_cross_after;
-                parent_main = parent_main.max(min_cross).min(max_cross);
+                min_cross = main_sum.max(content_size_main) + inset_cross_before + inset_cross_after;
+                parent_main = parent_main
+                    .max(main_sum.max(content_size_main))
+                    .min(max_cross - inset_cross_before - inset_cross_after);
             }
         }
     }
@@ -676,50 +2024,61 @@ where
     computed_main = computed_main.max(min_main).min(max_main);
     computed_cross = computed_cross.max(min_cross).min(max_cross);
 
-    let node_children = node
-        .children(tree)
-        .filter(|child| child.position_type(store).unwrap_or_default() == PositionType::SelfDirected)
-        .filter(|child| child.visible(store));
+    let node_children = ordered_children
+        .iter()
+        .copied()
+        .filter(|(child, _)| child.position_type(store).unwrap_or_default() == PositionType::SelfDirected)
+        .filter(|(child, breakpoint)| child.resolved_visibility(store, breakpoint) != Visibility::Collapsed);
 
     // Compute space and size of non-flexible self-directed children.
-    for child in node_children {
+    for (child, breakpoint) in node_children {
         // Get desired space and size.
-        let mut child_main_before = child.main_before(store, layout_type);
-        let child_main = child.main(store, layout_type);
-        let mut child_main_after = child.main_after(store, layout_type);
+        let mut child_main_before = child.main_before(store, layout_type, ctx);
+        let child_main = child.main(store, layout_type, ctx, &breakpoint);
+        let mut child_main_after = child.main_after(store, layout_type, ctx);
 
-        let mut child_cross_before = child.cross_before(store, layout_type);
-        let child_cross = child.cross(store, layout_type);
-        let mut child_cross_after = child.cross_after(store, layout_type);
+        let mut child_cross_before = child.cross_before(store, layout_type, ctx);
+        let child_cross = child.cross(store, layout_type, ctx, &breakpoint);
+        let mut child_cross_after = child.cross_after(store, layout_type, ctx);
 
         // Get fixed-size space and size constraints.
-        let child_min_cross_before = child.min_cross_before(store, layout_type);
-        let child_max_cross_before = child.max_cross_before(store, layout_type);
+        let mut child_min_cross_before = child.min_cross_before(store, layout_type, ctx);
+        let mut child_max_cross_before = child.max_cross_before(store, layout_type, ctx);
 
-        let child_min_cross_after = child.min_cross_after(store, layout_type);
-        let child_max_cross_after = child.max_cross_after(store, layout_type);
+        let mut child_min_cross_after = child.min_cross_after(store, layout_type, ctx);
+        let mut child_max_cross_after = child.max_cross_after(store, layout_type, ctx);
 
-        let child_min_main_before = child.min_main_before(store, layout_type);
-        let child_max_main_before = child.max_main_before(store, layout_type);
+        let mut child_min_main_before = child.min_main_before(store, layout_type, ctx);
+        let mut child_max_main_before = child.max_main_before(store, layout_type, ctx);
 
-        let child_min_main_after = child.min_main_after(store, layout_type);
-        let child_max_main_after = child.max_main_after(store, layout_type);
+        let mut child_min_main_after = child.min_main_after(store, layout_type, ctx);
+        let mut child_max_main_after = child.max_main_after(store, layout_type, ctx);
 
         // Apply parent child_space overrides to auto child space.
         if child_main_before.is_auto() {
             child_main_before = node_child_main_before;
+            child_min_main_before = node_min_child_main_before;
+            child_max_main_before = node_max_child_main_before;
         }
 
         if child_main_after.is_auto() {
             child_main_after = node_child_main_after;
+            child_min_main_after = node_min_child_main_after;
+            child_max_main_after = node_max_child_main_after;
         }
 
+        let (node_child_cross_before, node_child_cross_after) = child_cross_space(child);
+
         if child_cross_before.is_auto() {
             child_cross_before = node_child_cross_before;
+            child_min_cross_before = node_min_child_cross_before;
+            child_max_cross_before = node_max_child_cross_before;
         }
 
         if child_cross_after.is_auto() {
             child_cross_after = node_child_cross_after;
+            child_min_cross_after = node_min_child_cross_after;
+            child_max_cross_after = node_max_child_cross_after;
         }
 
         // Compute fixed-size child cross_before.
@@ -730,20 +2089,36 @@ where
         let computed_child_cross_after =
             child_cross_after.to_px_clamped(parent_cross, 0.0, child_min_cross_after, child_max_cross_after);
 
-        // Compute fixed-size child main_before.
-        let computed_child_main_before =
-            child_main_before.to_px_clamped(parent_main, 0.0, child_min_main_before, child_max_main_before);
+        // Compute fixed-size child main_before. Stretch space is constrained when it is computed below.
+        let computed_child_main_before = if child_main_before.is_stretch() {
+            0.0
+        } else {
+            child_main_before.to_px_clamped(parent_main, 0.0, child_min_main_before, child_max_main_before)
+        };
 
-        // Compute fixed-size child main_after.
-        let computed_child_main_after =
-            child_main_after.to_px_clamped(parent_main, 0.0, child_min_main_after, child_max_main_after);
+        // Compute fixed-size child main_after. Stretch space is constrained when it is computed below.
+        let computed_child_main_after = if child_main_after.is_stretch() {
+            0.0
+        } else {
+            child_main_after.to_px_clamped(parent_main, 0.0, child_min_main_after, child_max_main_after)
+        };
 
         let mut computed_child_main = 0.0;
         let mut computed_child_cross = 0.0;
 
         // Compute fixed-size child main.
         if !child_main.is_stretch() && !child_cross.is_stretch() {
-            let child_size = layout(child, layout_type, parent_main, parent_cross, cache, tree, store, sublayout);
+            let child_size = layout(
+                child,
+                layout_type,
+                parent_main,
+                parent_cross,
+                cache,
+                tree,
+                store,
+                sublayout,
+                &LayoutContext { parent_main, parent_cross, breakpoint: Some(breakpoint), ..*ctx },
+            );
 
             computed_child_main = child_size.main;
             computed_child_cross = child_size.cross;
@@ -751,12 +2126,14 @@ where
 
         children.push(ChildNode {
             node: child,
+            breakpoint,
             cross_before: computed_child_cross_before,
             cross: computed_child_cross,
             cross_after: computed_child_cross_after,
             main_before: computed_child_main_before,
             main: computed_child_main,
             main_after: computed_child_main_after,
+            measured: None,
         });
     }
 
@@ -766,17 +2143,29 @@ where
         .filter(|child| child.node.position_type(store).unwrap_or_default() == PositionType::SelfDirected)
         .enumerate()
     {
-        let mut child_cross_before = child.node.cross_before(store, layout_type);
-        let child_cross = child.node.cross(store, layout_type);
-        let mut child_cross_after = child.node.cross_after(store, layout_type);
+        let mut child_cross_before = child.node.cross_before(store, layout_type, ctx);
+        let child_cross = child.node.cross(store, layout_type, ctx, &child.breakpoint);
+        let mut child_cross_after = child.node.cross_after(store, layout_type, ctx);
+
+        // Get space constraints.
+        let mut child_min_cross_before = child.node.min_cross_before(store, layout_type, ctx);
+        let mut child_max_cross_before = child.node.max_cross_before(store, layout_type, ctx);
+        let mut child_min_cross_after = child.node.min_cross_after(store, layout_type, ctx);
+        let mut child_max_cross_after = child.node.max_cross_after(store, layout_type, ctx);
 
         // Apply child_space overrides.
+        let (node_child_cross_before, node_child_cross_after) = child_cross_space(child.node);
+
         if child_cross_before.is_auto() {
             child_cross_before = node_child_cross_before;
+            child_min_cross_before = node_min_child_cross_before;
+            child_max_cross_before = node_max_child_cross_before;
         }
 
         if child_cross_after.is_auto() {
             child_cross_after = node_child_cross_after;
+            child_min_cross_after = node_min_child_cross_after;
+            child_max_cross_after = node_max_child_cross_after;
         }
 
         let mut cross_flex_sum = 0.0;
@@ -784,10 +2173,8 @@ where
         // Collect stretch cross items.
         let mut cross_axis = SmallVec::<[StretchItem; 3]>::new();
         if let Stretch(factor) = child_cross_before {
-            let child_min_cross_before =
-                child.node.min_cross_before(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross_before =
-                child.node.max_cross_before(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross_before = child_min_cross_before.to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross_before = child_max_cross_before.to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -803,8 +2190,8 @@ where
         }
 
         if let Stretch(factor) = child_cross {
-            let child_min_cross = child.node.min_cross(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross = child.node.max_cross(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross = child.node.min_cross(store, layout_type, ctx).to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross = child.node.max_cross(store, layout_type, ctx).to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -814,8 +2201,8 @@ where
         }
 
         if let Stretch(factor) = child_cross_after {
-            let child_min_cross_after = child.node.min_cross_after(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross_after = child.node.max_cross_after(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross_after = child_min_cross_after.to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross_after = child_max_cross_after.to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -839,12 +2226,7 @@ where
             }
 
             // Compute free space in the cross axis.
-            let child_cross_free_space = parent_cross
-                - border_cross_before
-                - border_cross_after
-                - child.cross_before
-                - child.cross
-                - child.cross_after;
+            let child_cross_free_space = parent_cross - child.cross_before - child.cross - child.cross_after;
 
             // Total size violation in the cross axis.
             let mut total_violation = 0.0;
@@ -852,10 +2234,21 @@ where
             for item in cross_axis.iter_mut().filter(|item| !item.frozen) {
                 let actual_cross = (item.factor * child_cross_free_space / cross_flex_sum).round();
 
-                if item.item_type == ItemType::Size && !child.node.main(store, layout_type).is_stretch() {
-                    let child_size =
-                        layout(child.node, layout_type, parent_main, actual_cross, cache, tree, store, sublayout);
-                    if child.node.min_cross(store, layout_type).is_auto() {
+                if item.item_type == ItemType::Size
+                    && !child.node.main(store, layout_type, ctx, &child.breakpoint).is_stretch()
+                {
+                    let child_size = layout(
+                        child.node,
+                        layout_type,
+                        parent_main,
+                        actual_cross,
+                        cache,
+                        tree,
+                        store,
+                        sublayout,
+                        &LayoutContext { parent_main, parent_cross, breakpoint: Some(child.breakpoint), ..*ctx },
+                    );
+                    if child.node.min_cross(store, layout_type, ctx).is_auto() {
                         item.min = child_size.cross;
                     }
                     child.main = child_size.main;
@@ -897,7 +2290,7 @@ where
             }
         }
 
-        if child_position_type == PositionType::ParentDirected {
+        if child_position_type != PositionType::SelfDirected {
             cross_max = cross_max.max(child.cross_before + child.cross + child.cross_after);
         }
     }
@@ -908,17 +2301,27 @@ where
         .filter(|child| child.node.position_type(store).unwrap_or_default() == PositionType::SelfDirected)
         .enumerate()
     {
-        let mut child_main_before = child.node.main_before(store, layout_type);
-        let child_main = child.node.main(store, layout_type);
-        let mut child_main_after = child.node.main_after(store, layout_type);
+        let mut child_main_before = child.node.main_before(store, layout_type, ctx);
+        let child_main = child.node.main(store, layout_type, ctx, &child.breakpoint);
+        let mut child_main_after = child.node.main_after(store, layout_type, ctx);
+
+        // Get space constraints.
+        let mut child_min_main_before = child.node.min_main_before(store, layout_type, ctx);
+        let mut child_max_main_before = child.node.max_main_before(store, layout_type, ctx);
+        let mut child_min_main_after = child.node.min_main_after(store, layout_type, ctx);
+        let mut child_max_main_after = child.node.max_main_after(store, layout_type, ctx);
 
         // Apply child_space overrides.
         if child_main_before.is_auto() {
             child_main_before = node_child_main_before;
+            child_min_main_before = node_min_child_main_before;
+            child_max_main_before = node_max_child_main_before;
         }
 
         if child_main_after.is_auto() {
             child_main_after = node_child_main_after;
+            child_min_main_after = node_min_child_main_after;
+            child_max_main_after = node_max_child_main_after;
         }
 
         let mut child_main_flex_sum = 0.0;
@@ -926,8 +2329,8 @@ where
         // Collect stretch main items.
         let mut main_axis = SmallVec::<[StretchItem; 3]>::new();
         if let Stretch(factor) = child_main_before {
-            let child_min_main_before = child.node.min_main_before(store, layout_type).to_px(parent_main, DEFAULT_MIN);
-            let child_max_main_before = child.node.max_main_before(store, layout_type).to_px(parent_main, DEFAULT_MAX);
+            let child_min_main_before = child_min_main_before.to_px(parent_main, DEFAULT_MIN);
+            let child_max_main_before = child_max_main_before.to_px(parent_main, DEFAULT_MAX);
 
             child_main_flex_sum += factor;
 
@@ -940,16 +2343,16 @@ where
             ));
         }
         if let Stretch(factor) = child_main {
-            let child_min_main = child.node.min_main(store, layout_type).to_px(parent_main, DEFAULT_MIN);
-            let child_max_main = child.node.max_main(store, layout_type).to_px(parent_main, DEFAULT_MAX);
+            let child_min_main = child.node.min_main(store, layout_type, ctx).to_px(parent_main, DEFAULT_MIN);
+            let child_max_main = child.node.max_main(store, layout_type, ctx).to_px(parent_main, DEFAULT_MAX);
 
             child_main_flex_sum += factor;
 
             main_axis.push(StretchItem::new(index, factor, ItemType::Size, child_min_main, child_max_main));
         }
         if let Stretch(factor) = child_main_after {
-            let child_min_main_after = child.node.min_main_after(store, layout_type).to_px(parent_main, DEFAULT_MIN);
-            let child_max_main_after = child.node.max_main_after(store, layout_type).to_px(parent_main, DEFAULT_MAX);
+            let child_min_main_after = child_min_main_after.to_px(parent_main, DEFAULT_MIN);
+            let child_max_main_after = child_max_main_after.to_px(parent_main, DEFAULT_MAX);
 
             child_main_flex_sum += factor;
 
@@ -969,12 +2372,7 @@ where
             }
 
             // Compute free space in the main axis.
-            let child_main_free_space = parent_main
-                - border_main_before
-                - border_main_after
-                - child.main_before
-                - child.main
-                - child.main_after;
+            let child_main_free_space = parent_main - child.main_before - child.main - child.main_after;
 
             // Total size violation in the main axis.
             let mut total_violation = 0.0;
@@ -987,14 +2385,19 @@ where
                         child.node,
                         layout_type,
                         actual_main,
-                        if child.node.cross(store, layout_type).is_stretch() { child.cross } else { parent_cross },
+                        if child.node.cross(store, layout_type, ctx, &child.breakpoint).is_stretch() {
+                            child.cross
+                        } else {
+                            parent_cross
+                        },
                         cache,
                         tree,
                         store,
                         sublayout,
+                        &LayoutContext { parent_main, parent_cross, breakpoint: Some(child.breakpoint), ..*ctx },
                     );
                     child.cross = child_size.cross;
-                    if child.node.min_main(store, layout_type).is_auto() {
+                    if child.node.min_main(store, layout_type, ctx).is_auto() {
                         item.min = child_size.main;
                     }
                 }
@@ -1033,24 +2436,35 @@ where
         }
     }
 
+    // Include the space and size of self-directed children which contribute to the auto size of the node.
+    let mut num_contributing_children = 0;
+    for child in children.iter().filter(|child| {
+        child.node.position_type(store).unwrap_or_default() == PositionType::SelfDirected
+            && child.node.contributes_to_size(store).unwrap_or_default()
+    }) {
+        main_sum = main_sum.max(child.main_before + child.main + child.main_after);
+        cross_max = cross_max.max(child.cross_before + child.cross + child.cross_after);
+        num_contributing_children += 1;
+    }
+
     // Determine auto main and cross size from space and size of children.
-    if num_parent_directed_children != 0 {
-        if main.is_auto() || node.min_main(store, parent_layout_type).is_auto() {
+    if num_parent_directed_children != 0 || num_contributing_children != 0 {
+        if main.is_auto() || node.min_main(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_main = main_sum + border_main_before + border_main_after;
-                // parent_main = parent_main.max(min_main).min(max_main);
+                min_main = main_sum.max(content_size_main) + inset_main_before + inset_main_after;
+                // parent_main = parent_main.max(main_sum).min(max_main - inset_main_before - inset_main_after);
             } else {
-                min_main = cross_max + border_main_before + border_main_after;
-                parent_cross = parent_cross.max(min_main).min(max_main);
+                min_main = cross_max + inset_main_before + inset_main_after;
+                parent_cross = parent_cross.max(cross_max).min(max_main - inset_main_before - inset_main_after);
             }
         }
-        if cross.is_auto() || node.min_cross(store, parent_layout_type).is_auto() {
+        if cross.is_auto() || node.min_cross(store, parent_layout_type, ctx).is_auto() {
             if parent_layout_type == layout_type {
-                min_cross = cross_max + border_cross_before + border_cross_after;
-                parent_cross = parent_cross.max(min_cross).min(max_cross);
+                min_cross = cross_max + inset_cross_before + inset_cross_after;
+                parent_cross = parent_cross.max(cross_max).min(max_cross - inset_cross_before - inset_cross_after);
             } else {
-                min_cross = main_sum + border_cross_before + border_cross_after;
-                // parent_main = parent_main.max(min_cross).min(max_cross);
+                min_cross = main_sum.max(content_size_main) + inset_cross_before + inset_cross_after;
+                // parent_main = parent_main.max(main_sum).min(max_cross - inset_cross_before - inset_cross_after);
             }
         }
     }
@@ -1058,25 +2472,167 @@ where
     computed_main = computed_main.max(min_main).min(max_main);
     computed_cross = computed_cross.max(min_cross).min(max_cross);
 
+    // Resolve the percentage space and size of parent-directed children against the final size of the node, if the size
+    // was unknown when they were laid out. Percentages are treated as auto for this size, so they do not change it.
+    if indefinite_main || indefinite_cross {
+        (parent_main, parent_cross) = if parent_layout_type == layout_type {
+            (computed_main, computed_cross)
+        } else {
+            (computed_cross, computed_main)
+        };
+        parent_main = (parent_main - content_main_before - content_main_after).max(0.0);
+        parent_cross = (parent_cross - content_cross_before - content_cross_after).max(0.0);
+
+        // Parent-directed children are at the start of the list of children.
+        for index in 0..num_parent_directed_children {
+            let next_main_before = children.get(index + 1).map(|next| next.node.main_before(store, layout_type, ctx));
+            let child = &mut children[index];
+
+            if indefinite_main {
+                let mut child_main_before = child.node.main_before(store, layout_type, ctx);
+                let mut child_main_after = child.node.main_after(store, layout_type, ctx);
+
+                let mut child_min_main_before = child.node.min_main_before(store, layout_type, ctx);
+                let mut child_max_main_before = child.node.max_main_before(store, layout_type, ctx);
+                let mut child_min_main_after = child.node.min_main_after(store, layout_type, ctx);
+                let mut child_max_main_after = child.node.max_main_after(store, layout_type, ctx);
+
+                // Apply parent child_space overrides to auto child space.
+                if child_main_before.is_auto() && first == Some(index) {
+                    child_main_before = node_child_main_before;
+                    child_min_main_before = node_min_child_main_before;
+                    child_max_main_before = node_max_child_main_before;
+                }
+
+                if child_main_after.is_auto() {
+                    if last == Some(index) {
+                        child_main_after = node_child_main_after;
+                        child_min_main_after = node_min_child_main_after;
+                        child_max_main_after = node_max_child_main_after;
+                    } else if next_main_before.is_some_and(|next_main_before| next_main_before.is_auto()) {
+                        child_main_after = node_child_main_between;
+                        child_min_main_after = node_min_child_main_between;
+                        child_max_main_after = node_max_child_main_between;
+                    }
+                }
+
+                if child_main_before.is_percentage_based() {
+                    child.main_before =
+                        child_main_before.to_px_clamped(parent_main, 0.0, child_min_main_before, child_max_main_before);
+                }
+
+                if child_main_after.is_percentage_based() {
+                    child.main_after =
+                        child_main_after.to_px_clamped(parent_main, 0.0, child_min_main_after, child_max_main_after);
+                }
+            }
+
+            if indefinite_cross {
+                let mut child_cross_before = child.node.cross_before(store, layout_type, ctx);
+                let mut child_cross_after = child.node.cross_after(store, layout_type, ctx);
+
+                let mut child_min_cross_before = child.node.min_cross_before(store, layout_type, ctx);
+                let mut child_max_cross_before = child.node.max_cross_before(store, layout_type, ctx);
+                let mut child_min_cross_after = child.node.min_cross_after(store, layout_type, ctx);
+                let mut child_max_cross_after = child.node.max_cross_after(store, layout_type, ctx);
+
+                // Apply child_space overrides.
+                let (node_child_cross_before, node_child_cross_after) = child_cross_space(child.node);
+
+                if child_cross_before.is_auto() {
+                    child_cross_before = node_child_cross_before;
+                    child_min_cross_before = node_min_child_cross_before;
+                    child_max_cross_before = node_max_child_cross_before;
+                }
+
+                if child_cross_after.is_auto() {
+                    child_cross_after = node_child_cross_after;
+                    child_min_cross_after = node_min_child_cross_after;
+                    child_max_cross_after = node_max_child_cross_after;
+                }
+
+                if child_cross_before.is_percentage_based() {
+                    child.cross_before = child_cross_before.to_px_clamped(
+                        parent_cross,
+                        0.0,
+                        child_min_cross_before,
+                        child_max_cross_before,
+                    );
+                }
+
+                if child_cross_after.is_percentage_based() {
+                    child.cross_after = child_cross_after.to_px_clamped(
+                        parent_cross,
+                        0.0,
+                        child_min_cross_after,
+                        child_max_cross_after,
+                    );
+                }
+            }
+
+            let child_main = child.node.main(store, layout_type, ctx, &child.breakpoint);
+            let child_cross = child.node.cross(store, layout_type, ctx, &child.breakpoint);
+
+            if (indefinite_main && child_main.is_percentage_based())
+                || (indefinite_cross && child_cross.is_percentage_based())
+            {
+                let child_size = layout(
+                    child.node,
+                    layout_type,
+                    if child_main.is_stretch() { child.main } else { parent_main },
+                    if child_cross.is_stretch() { child.cross } else { parent_cross },
+                    cache,
+                    tree,
+                    store,
+                    sublayout,
+                    &LayoutContext { parent_main, parent_cross, breakpoint: Some(child.breakpoint), ..*ctx },
+                );
+                child.main = child_size.main;
+                child.cross = child_size.cross;
+            }
+        }
+    }
+
+    // Determine whether the cross size of the node, on the axes of the children, is determined by its children.
+    let auto_cross = if parent_layout_type == layout_type {
+        cross.is_auto() || node.min_cross(store, parent_layout_type, ctx).is_auto()
+    } else {
+        main.is_auto() || node.min_main(store, parent_layout_type, ctx).is_auto()
+    };
+
     // Compute stretch cross_before and stretch cross_after for auto cross children.
+    // If the cross size of the node is determined by its children, parent-directed children with a stretch cross size
+    // are laid out again now that the final cross size of the node is known, unless their cross size is unchanged.
     // TODO: I think this only needs to be done for parent-directed children...
     for (index, child) in children
         .iter_mut()
         // .filter(|child| {
-        //     child.node.cross(store, layout_type).is_auto() || child.node.min_cross(store, layout_type).is_auto()
+        //     child.node.cross(store, layout_type, ctx, &child.breakpoint).is_auto() || child.node.min_cross(store, layout_type, ctx).is_auto()
         // })
         .enumerate()
     {
-        let mut child_cross_before = child.node.cross_before(store, layout_type);
-        let mut child_cross_after = child.node.cross_after(store, layout_type);
+        let mut child_cross_before = child.node.cross_before(store, layout_type, ctx);
+        let mut child_cross_after = child.node.cross_after(store, layout_type, ctx);
+
+        // Get space constraints.
+        let mut child_min_cross_before = child.node.min_cross_before(store, layout_type, ctx);
+        let mut child_max_cross_before = child.node.max_cross_before(store, layout_type, ctx);
+        let mut child_min_cross_after = child.node.min_cross_after(store, layout_type, ctx);
+        let mut child_max_cross_after = child.node.max_cross_after(store, layout_type, ctx);
 
         // Apply child_space overrides.
+        let (node_child_cross_before, node_child_cross_after) = child_cross_space(child.node);
+
         if child_cross_before.is_auto() {
             child_cross_before = node_child_cross_before;
+            child_min_cross_before = node_min_child_cross_before;
+            child_max_cross_before = node_max_child_cross_before;
         }
 
         if child_cross_after.is_auto() {
             child_cross_after = node_child_cross_after;
+            child_min_cross_after = node_min_child_cross_after;
+            child_max_cross_after = node_max_child_cross_after;
         }
 
         let mut cross_flex_sum = 0.0;
@@ -1084,10 +2640,8 @@ where
         // Collect stretch cross items.
         let mut cross_axis = SmallVec::<[StretchItem; 3]>::new();
         if let Stretch(factor) = child_cross_before {
-            let child_min_cross_before =
-                child.node.min_cross_before(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross_before =
-                child.node.max_cross_before(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross_before = child_min_cross_before.to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross_before = child_max_cross_before.to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -1103,8 +2657,8 @@ where
         }
 
         if let Stretch(factor) = child_cross_after {
-            let child_min_cross_after = child.node.min_cross_after(store, layout_type).to_px(parent_cross, DEFAULT_MIN);
-            let child_max_cross_after = child.node.max_cross_after(store, layout_type).to_px(parent_cross, DEFAULT_MAX);
+            let child_min_cross_after = child_min_cross_after.to_px(parent_cross, DEFAULT_MIN);
+            let child_max_cross_after = child_max_cross_after.to_px(parent_cross, DEFAULT_MAX);
 
             cross_flex_sum += factor;
 
@@ -1121,6 +2675,22 @@ where
 
         let child_position_type = child.node.position_type(store).unwrap_or_default();
 
+        // The cross size the child was laid out with, which is kept if it does not change.
+        let laid_out_cross = child.cross;
+
+        if let Stretch(factor) = child.node.cross(store, layout_type, ctx, &child.breakpoint) {
+            if auto_cross && child_position_type != PositionType::SelfDirected {
+                let child_min_cross = child.node.min_cross(store, layout_type, ctx).to_px(parent_cross, DEFAULT_MIN);
+                let child_max_cross = child.node.max_cross(store, layout_type, ctx).to_px(parent_cross, DEFAULT_MAX);
+
+                cross_flex_sum += factor;
+
+                child.cross = 0.0;
+
+                cross_axis.push(StretchItem::new(index, factor, ItemType::Size, child_min_cross, child_max_cross));
+            }
+        }
+
         loop {
             // If all stretch items are frozen, exit the loop.
             if cross_axis.iter().all(|item| item.frozen) {
@@ -1128,12 +2698,7 @@ where
             }
 
             // Compute free space in the cross axis.
-            let child_cross_free_space = parent_cross
-                - border_cross_before
-                - border_cross_after
-                - child.cross_before
-                - child.cross
-                - child.cross_after;
+            let child_cross_free_space = parent_cross - child.cross_before - child.cross - child.cross_after;
 
             // Total size violation in the cross axis.
             let mut total_violation = 0.0;
@@ -1165,23 +2730,73 @@ where
                             child.cross_before = item.computed;
                         }
 
+                        ItemType::Size if item.computed == laid_out_cross => {
+                            child.cross = laid_out_cross;
+                        }
+
+                        ItemType::Size => {
+                            let child_size = layout(
+                                child.node,
+                                layout_type,
+                                if child.node.main(store, layout_type, ctx, &child.breakpoint).is_stretch() {
+                                    child.main
+                                } else {
+                                    parent_main
+                                },
+                                item.computed,
+                                cache,
+                                tree,
+                                store,
+                                sublayout,
+                                &LayoutContext {
+                                    parent_main,
+                                    parent_cross,
+                                    breakpoint: Some(child.breakpoint),
+                                    ..*ctx
+                                },
+                            );
+                            child.main = child_size.main;
+                            child.cross = child_size.cross;
+                        }
+
                         ItemType::After => {
                             child.cross_after = item.computed;
                         }
-
-                        _ => {}
                     }
                 }
             }
         }
 
-        if child_position_type == PositionType::ParentDirected {
+        if child_position_type != PositionType::SelfDirected {
             cross_max = cross_max.max(child.cross_before + child.cross + child.cross_after);
         }
     }
 
+    // Clear the cached size and position of collapsed children and their descendants.
+    for (child, _) in ordered_children
+        .iter()
+        .copied()
+        .filter(|(child, breakpoint)| child.resolved_visibility(store, breakpoint) == Visibility::Collapsed)
+    {
+        clear_bounds(child, cache, tree, ctx);
+        set_skipped(child, true, ctx);
+    }
+
+    // Report which parent-directed children are overflowed, clearing the cached size and position of those which are.
+    for (index, (child, _)) in parent_directed_children.iter().copied().enumerate() {
+        cache.set_overflowed(child, overflowed[index]);
+        if overflowed[index] {
+            clear_bounds(child, cache, tree, ctx);
+            set_skipped(child, true, ctx);
+        }
+    }
+
+    // The far edges of the content on the axes of the children, including the content size and the space of the children.
+    let mut content_main_end = content_main_before + content_size_main;
+    let mut content_cross_end = content_cross_before + content_size_cross;
+
     // Set size and position of children in the cache.
-    let mut main_pos = 0.0;
+    let mut main_pos = content_size_before;
     for child in children.iter() {
         let child_position_type = child.node.position_type(store).unwrap_or_default();
         match child_position_type {
@@ -1189,28 +2804,228 @@ where
                 cache.set_rect(
                     child.node,
                     layout_type,
-                    child.main_before + border_main_before,
-                    child.cross_before + border_cross_before,
+                    child.main_before + content_main_before - scroll_main,
+                    child.cross_before + content_cross_before - scroll_cross,
                     child.main,
                     child.cross,
                 );
+                content_main_end =
+                    content_main_end.max(child.main_before + child.main + child.main_after + content_main_before);
             }
 
-            PositionType::ParentDirected => {
+            PositionType::ParentDirected | PositionType::Sticky => {
                 main_pos += child.main_before;
                 cache.set_rect(
                     child.node,
                     layout_type,
-                    main_pos + border_main_before,
-                    child.cross_before + border_cross_before,
+                    main_pos + content_main_before - scroll_main,
+                    child.cross_before + content_cross_before - scroll_cross,
                     child.main,
                     child.cross,
                 );
                 main_pos += child.main + child.main_after;
+                content_main_end = content_main_end.max(main_pos + content_main_before);
             }
         };
+        set_skipped(child.node, false, ctx);
+        translate(child.node, cache, store, ctx);
+        content_cross_end =
+            content_cross_end.max(child.cross_before + child.cross + child.cross_after + content_cross_before);
+    }
+
+    // Report the content extent of the node, which is its size expanded to cover its content.
+    report_content_extent(
+        node,
+        parent_layout_type,
+        layout_type,
+        Size { main: computed_main, cross: computed_cross },
+        content_main_end + content_main_after,
+        content_cross_end + content_cross_after,
+        cache,
+    );
+
+    // Move sticky descendants of a scrolling node into its visible region, which is its content box.
+    if overflow == Overflow::Scroll {
+        let (width, height) =
+            if parent_layout_type.is_row() { (computed_main, computed_cross) } else { (computed_cross, computed_main) };
+        let (visible_x, visible_y) = if layout_type.is_row() {
+            (content_main_before, content_cross_before)
+        } else {
+            (content_cross_before, content_main_before)
+        };
+        stick(node, 0.0, 0.0, width, height, visible_x, visible_y, cache, tree, store, ctx);
     }
 
     // Return the computed size, propagating it back up the tree.
-    Size { main: computed_main, cross: computed_cross }
+    let size = Size { main: computed_main, cross: computed_cross };
+    measure_size_group(node, store, parent_layout_type, auto_main, auto_cross, size, ctx);
+    size
+}
+
+/// Moves the sticky descendants of a scrolling node to the top-left of its visible region, keeping them within the
+/// bounds of their parents.
+///
+/// The offset is the position of the given node relative to the scrolling node. Nodes which were skipped by the layout
+/// of their parents, and the descendants of nested scrolling nodes, which are moved by the nested node, are skipped.
+#[allow(clippy::too_many_arguments)]
+fn stick<N, C>(
+    node: &N,
+    offset_x: f32,
+    offset_y: f32,
+    width: f32,
+    height: f32,
+    visible_x: f32,
+    visible_y: f32,
+    cache: &mut C,
+    tree: &<N as Node>::Tree,
+    store: &<N as Node>::Store,
+    ctx: &LayoutContext<N::CacheKey>,
+) where
+    N: Node,
+    C: Cache<Node = N>,
+{
+    // Only the items of a virtual list within its visible window are laid out.
+    let range =
+        if node.virtual_list(store).is_some() { cache.visible_range(node).unwrap_or_default() } else { 0..usize::MAX };
+    for child in node.children(tree).skip(range.start).take(range.len()) {
+        if is_skipped(child, ctx) {
+            continue;
+        }
+
+        let child_width = cache.width(child);
+        let child_height = cache.height(child);
+        let mut posx = cache.posx(child);
+        let mut posy = cache.posy(child);
+        if child.position_type(store).unwrap_or_default() == PositionType::Sticky {
+            posx = posx.max((visible_x - offset_x).min(width - child_width));
+            posy = posy.max((visible_y - offset_y).min(height - child_height));
+            cache.set_bounds(child, posx, posy, child_width, child_height);
+        }
+
+        if child.overflow(store).unwrap_or_default() != Overflow::Scroll {
+            stick(
+                child,
+                offset_x + posx,
+                offset_y + posy,
+                child_width,
+                child_height,
+                visible_x,
+                visible_y,
+                cache,
+                tree,
+                store,
+                ctx,
+            );
+        }
+    }
+}
+
+/// Reports the content extent of the given node, which is its size expanded to cover the far edges of its content.
+///
+/// The size is on the axes of the parent of the node, while the far edges of the content, which include the insets
+/// of the node, are on the axes of its children.
+fn report_content_extent<N, C>(
+    node: &N,
+    parent_layout_type: LayoutType,
+    layout_type: LayoutType,
+    size: Size,
+    content_main_end: f32,
+    content_cross_end: f32,
+    cache: &mut C,
+) where
+    N: Node,
+    C: Cache<Node = N>,
+{
+    let (extent_main, extent_cross) =
+        if parent_layout_type == layout_type { (size.main, size.cross) } else { (size.cross, size.main) };
+    let extent_main = extent_main.max(content_main_end);
+    let extent_cross = extent_cross.max(content_cross_end);
+    if layout_type.is_row() {
+        cache.set_content_extent(node, extent_main, extent_cross)
+    } else {
+        cache.set_content_extent(node, extent_cross, extent_main)
+    }
+}
+
+/// Offsets the cached position of the given child by its translation, once the child is positioned by its parent, so
+/// that the translation does not affect the layout of its siblings.
+fn translate<N, C>(child: &N, cache: &mut C, store: &<N as Node>::Store, ctx: &LayoutContext<N::CacheKey>)
+where
+    N: Node,
+    C: Cache<Node = N>,
+{
+    let width = cache.width(child);
+    let height = cache.height(child);
+    let font_size = child.font_size(store);
+    let translate_x = child.translate_x(store).unwrap_or_default().resolve(ctx, font_size).to_px(width, 0.0);
+    let translate_y = child.translate_y(store).unwrap_or_default().resolve(ctx, font_size).to_px(height, 0.0);
+    if translate_x != 0.0 || translate_y != 0.0 {
+        cache.set_bounds(child, cache.posx(child) + translate_x, cache.posy(child) + translate_y, width, height);
+    }
+}
+
+/// Records the size of the given node if it is in a shared size group, on the axes on which it is auto-sized, replacing
+/// the size measured by a previous layout of the node.
+fn measure_size_group<N: Node>(
+    node: &N,
+    store: &<N as Node>::Store,
+    parent_layout_type: LayoutType,
+    auto_main: bool,
+    auto_cross: bool,
+    size: Size,
+    ctx: &LayoutContext<N::CacheKey>,
+) {
+    let (Some(measured_sizes), Some(id)) = (ctx.measured_sizes, node.size_group(store)) else {
+        return;
+    };
+
+    let main = if auto_main { size.main } else { 0.0 };
+    let cross = if auto_cross { size.cross } else { 0.0 };
+    let (width, height) = if parent_layout_type.is_row() { (main, cross) } else { (cross, main) };
+
+    measured_sizes.borrow_mut().insert(node.key(), (id, width, height));
+}
+
+/// Collects the identifier of each shared size group from the measured sizes of the nodes in the groups, along with
+/// the largest width and height of the auto-sized nodes in the group.
+pub(crate) fn size_groups<K>(measured_sizes: &HashMap<K, (u32, f32, f32)>) -> HashMap<u32, (f32, f32)> {
+    let mut groups: HashMap<u32, (f32, f32)> = HashMap::new();
+    for &(id, width, height) in measured_sizes.values() {
+        let group = groups.entry(id).or_insert((width, height));
+        group.0 = group.0.max(width);
+        group.1 = group.1.max(height);
+    }
+    groups
+}
+
+/// Records whether the given child was skipped by the layout of its parent, because it is collapsed or overflowed, so
+/// that sticky nodes are not moved into skipped subtrees.
+fn set_skipped<N: Node>(child: &N, skipped: bool, ctx: &LayoutContext<N::CacheKey>) {
+    if skipped {
+        ctx.skipped.borrow_mut().insert(child.key());
+    } else {
+        ctx.skipped.borrow_mut().remove(&child.key());
+    }
+}
+
+/// Returns whether the given child was skipped by the latest layout of its parent.
+fn is_skipped<N: Node>(child: &N, ctx: &LayoutContext<N::CacheKey>) -> bool {
+    ctx.skipped.borrow().contains(&child.key())
+}
+
+/// Clears the cached size and position of the given node and all of its descendants, along with their measured sizes.
+fn clear_bounds<N, C>(node: &N, cache: &mut C, tree: &<N as Node>::Tree, ctx: &LayoutContext<N::CacheKey>)
+where
+    N: Node,
+    C: Cache<Node = N>,
+{
+    cache.clear_bounds(node);
+
+    if let Some(measured_sizes) = ctx.measured_sizes {
+        measured_sizes.borrow_mut().remove(&node.key());
+    }
+
+    for child in node.children(tree) {
+        clear_bounds(child, cache, tree, ctx);
+    }
 }
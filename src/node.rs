@@ -1,4 +1,13 @@
-use crate::{layout, types::*, Cache};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+use crate::{layout, layout::size_groups, types::*, Cache};
+
+/// The font size of the root node if it does not specify a font size.
+const DEFAULT_FONT_SIZE: f32 = 16.0;
 
 /// A `Node` represents a layout element which can be sized and positioned based on
 /// a number of layout properties.
@@ -21,7 +30,9 @@ pub trait Node: Sized {
     where
         Self: 't;
     /// A type representing a key to store and retrieve values from the [`Cache`].
-    type CacheKey;
+    ///
+    /// The key is also used to identify the node during layout, so it must be unique to each node in the tree.
+    type CacheKey: Eq + Hash;
     /// A type representing a context which can be used to save/load state when computing [content size](crate::Node::content_size).
     /// For example, a `TextContext` which could be used to measure (and cache) the size of text, which could
     /// then be used to size an `Auto` layout node using content size.
@@ -64,7 +75,48 @@ pub trait Node: Sized {
 
         cache.set_bounds(self, cache.posx(self), cache.posy(self), width, height);
 
-        layout(self, LayoutType::Column, height, width, cache, tree, store, sublayout)
+        let font_size = self.font_size(store).unwrap_or(DEFAULT_FONT_SIZE);
+
+        let skipped = RefCell::new(HashSet::new());
+        let measured_sizes = RefCell::new(HashMap::new());
+
+        let ctx = LayoutContext {
+            root_width: width,
+            root_height: height,
+            root_font_size: font_size,
+            font_size,
+            parent_main: height,
+            parent_cross: width,
+            indefinite_main: false,
+            indefinite_cross: false,
+            query: LayoutQuery { parent_width: width, parent_height: height },
+            size_groups: &HashMap::new(),
+            measured_sizes: Some(&measured_sizes),
+            uniform_main: None,
+            uniform_cross: None,
+            breakpoint: None,
+            skipped: &skipped,
+        };
+
+        let size = layout(self, LayoutType::Column, height, width, cache, tree, store, sublayout, &ctx);
+
+        // Lay out the tree again if it contains shared size groups, with the sizes measured by the first pass.
+        let groups = size_groups(&measured_sizes.borrow());
+        if groups.is_empty() {
+            return size;
+        }
+
+        layout(
+            self,
+            LayoutType::Column,
+            height,
+            width,
+            cache,
+            tree,
+            store,
+            sublayout,
+            &LayoutContext { size_groups: &groups, measured_sizes: None, ..ctx },
+        )
     }
 
     /// Returns a key which can be used to set/get computed layout data from the [`cache`](crate::Cache).
@@ -73,8 +125,11 @@ pub trait Node: Sized {
     /// Returns an iterator over the children of the node.
     fn children<'t>(&'t self, tree: &'t Self::Tree) -> Self::ChildIter<'t>;
 
-    /// Returns a boolean representing whether the node is visible to layout.
-    fn visible(&self, store: &Self::Store) -> bool;
+    /// Returns the visibility of the node.
+    ///
+    /// Nodes which are [`Hidden`](crate::Visibility::Hidden) are laid out as normal, while nodes which are
+    /// [`Collapsed`](crate::Visibility::Collapsed) are skipped by layout along with their children.
+    fn visibility(&self, store: &Self::Store) -> Option<Visibility>;
 
     /// Returns the layout type of the node.
     fn layout_type(&self, store: &Self::Store) -> Option<LayoutType>;
@@ -82,6 +137,44 @@ pub trait Node: Sized {
     /// Returns the position type of the node.
     fn position_type(&self, store: &Self::Store) -> Option<PositionType>;
 
+    /// Returns whether a self-directed node contributes to the size of its parent when the parent size is auto.
+    ///
+    /// Parent-directed nodes always contribute to the size of their parent. Defaults to `false` if not set.
+    fn contributes_to_size(&self, store: &Self::Store) -> Option<bool>;
+
+    /// Returns the order of a parent-directed node among its siblings.
+    ///
+    /// Parent-directed children are arranged in ascending order, and in tree order for equal orders, without changing
+    /// the order of the tree. Defaults to `0` if not set.
+    fn order(&self, store: &Self::Store) -> Option<i32>;
+
+    /// Returns how the node handles parent-directed children which do not fit within it on the main-axis.
+    fn overflow(&self, store: &Self::Store) -> Option<Overflow>;
+
+    /// Returns the overflow priority of the node.
+    ///
+    /// When the parent [collapses](crate::Overflow::Collapse) overflowing children, children with the lowest priority
+    /// are collapsed first. Nodes without an overflow priority are never collapsed.
+    fn overflow_priority(&self, store: &Self::Store) -> Option<i32>;
+
+    /// Returns the horizontal scroll offset of the node in pixels.
+    ///
+    /// The children of a [scrolling](crate::Overflow::Scroll) node are moved left by the scroll offset.
+    fn scroll_x(&self, store: &Self::Store) -> Option<f32>;
+
+    /// Returns the vertical scroll offset of the node in pixels.
+    ///
+    /// The children of a [scrolling](crate::Overflow::Scroll) node are moved up by the scroll offset.
+    fn scroll_y(&self, store: &Self::Store) -> Option<f32>;
+
+    /// Returns the items of the node if it is a virtual list.
+    ///
+    /// The children of a virtual list are its items, each placed in a slot of the item extent on the main-axis.
+    /// Only the children within the visible window of the node, which is its content box offset by its
+    /// [scroll offset](crate::Node::scroll_y), and the overscan around it are laid out. The remaining children
+    /// are not visited, except to clear the bounds of those which have left the window since the previous layout.
+    fn virtual_list(&self, store: &Self::Store) -> Option<VirtualList>;
+
     /// Returns the desired width of the node.
     fn width(&self, store: &Self::Store) -> Option<Units>;
 
@@ -100,7 +193,22 @@ pub trait Node: Sized {
     /// Returns the desired bottom-side space of the node.
     fn bottom(&self, store: &Self::Store) -> Option<Units>;
 
-    /// Returns the width and height of the node if its desired width and/or desired height are auto and the node has no children.
+    /// Returns the horizontal translation of the node.
+    ///
+    /// The translation offsets the position of the node after it is laid out, so it does not affect the layout of its
+    /// siblings or the size of its parent. Percentages are relative to the width of the node. The translation of the
+    /// root node is ignored.
+    fn translate_x(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the vertical translation of the node.
+    ///
+    /// The translation offsets the position of the node after it is laid out, so it does not affect the layout of its
+    /// siblings or the size of its parent. Percentages are relative to the height of the node. The translation of the
+    /// root node is ignored.
+    fn translate_y(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the width and height of the node if its desired width and/or desired height are auto and the node has no children,
+    /// or if the content size is combined with the children of the node by its [`ContentSizeMode`].
     /// This can be used to size the node based on visual content (such as text), or to apply an aspect ratio size constraint.
     fn content_size(
         &self,
@@ -110,6 +218,18 @@ pub trait Node: Sized {
         parent_height: Option<f32>,
     ) -> Option<(f32, f32)>;
 
+    /// Returns how the content size of the node is combined with the size of its children.
+    fn content_size_mode(&self, store: &Self::Store) -> Option<ContentSizeMode>;
+
+    /// Returns whether the parent-directed children of the node are given the same size, the size of the largest child.
+    fn uniform_children(&self, store: &Self::Store) -> Option<UniformChildren>;
+
+    /// Returns the identifier of the shared size group of the node.
+    ///
+    /// The `Auto` width and height of the nodes in a group, which can have different parents, resolve to the
+    /// largest auto width and height of the nodes in the group. This requires an extra layout pass.
+    fn size_group(&self, store: &Self::Store) -> Option<u32>;
+
     /// Returns the desired left-side child-space of the node.
     fn child_left(&self, store: &Self::Store) -> Option<Units>;
 
@@ -128,6 +248,79 @@ pub trait Node: Sized {
     /// Returns the desired space to be applied between the children of the node on the horizontal axis.
     fn col_between(&self, store: &Self::Store) -> Option<Units>;
 
+    /// Returns the number of columns of a [multi-column](crate::LayoutType::MultiColumn) node.
+    fn column_count(&self, store: &Self::Store) -> Option<usize>;
+
+    /// Returns the minimum width of the columns of a [multi-column](crate::LayoutType::MultiColumn) node,
+    /// which determines the number of columns if the column count is not set.
+    fn min_column_width(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the angle, in degrees clockwise from the top, at which the children of a
+    /// [radial](crate::LayoutType::Radial) node start. Defaults to `0.0` if not set.
+    fn start_angle(&self, store: &Self::Store) -> Option<f32>;
+
+    /// Returns the angle, in degrees, covered by the children of a [radial](crate::LayoutType::Radial) node.
+    ///
+    /// If the span is a full circle the children are spaced evenly around it, otherwise the first and last children
+    /// are placed at the ends of the arc. Defaults to `360.0` if not set.
+    fn angular_span(&self, store: &Self::Store) -> Option<f32>;
+
+    /// Returns the distance from the centre of a [radial](crate::LayoutType::Radial) node to the centres of its
+    /// children. Percentages are relative to half of the smaller side of the content box, ignoring auto-sized sides,
+    /// and are zero if both sides are auto. Defaults to 100% if not set.
+    fn radius(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the edge to which the node is attached when its parent is a [dock](crate::LayoutType::Dock) node.
+    ///
+    /// The last parent-directed child of a dock node fills the remaining area, regardless of its dock side.
+    /// Defaults to [`Dock::Left`](crate::Dock::Left) if not set.
+    fn dock(&self, store: &Self::Store) -> Option<Dock>;
+
+    /// Returns the minimum left-side child-space of the node.
+    fn min_child_left(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the minimum right-side child-space of the node.
+    fn min_child_right(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the minimum top-side child-space of the node.
+    fn min_child_top(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the minimum bottom-side child-space of the node.
+    fn min_child_bottom(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the maximum left-side child-space of the node.
+    fn max_child_left(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the maximum right-side child-space of the node.
+    fn max_child_right(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the maximum top-side child-space of the node.
+    fn max_child_top(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the maximum bottom-side child-space of the node.
+    fn max_child_bottom(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the minimum space between the children of the node on the vertical axis.
+    fn min_row_between(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the maximum space between the children of the node on the vertical axis.
+    fn max_row_between(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the minimum space between the children of the node on the horizontal axis.
+    fn min_col_between(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the maximum space between the children of the node on the horizontal axis.
+    fn max_col_between(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the alignment of the children of the node along its main axis.
+    fn main_alignment(&self, store: &Self::Store) -> Option<MainAlignment>;
+
+    /// Returns the alignment of the children of the node along its cross axis.
+    fn cross_alignment(&self, store: &Self::Store) -> Option<CrossAlignment>;
+
+    /// Returns the alignment of the node along the cross axis of its parent, overriding the cross alignment of the parent.
+    fn align_self(&self, store: &Self::Store) -> Option<CrossAlignment>;
+
     /// Returns the minimum width of the node.
     fn min_width(&self, store: &Self::Store) -> Option<Units>;
 
@@ -175,148 +368,543 @@ pub trait Node: Sized {
 
     /// Returns the bottom-side border width of the node.
     fn border_bottom(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the box sizing of the node, which determines whether its border and padding are included in its size.
+    fn box_sizing(&self, store: &Self::Store) -> Option<BoxSizing>;
+
+    /// Returns the left-side padding of the node.
+    fn padding_left(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the right-side padding of the node.
+    fn padding_right(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the top-side padding of the node.
+    fn padding_top(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the bottom-side padding of the node.
+    fn padding_bottom(&self, store: &Self::Store) -> Option<Units>;
+
+    /// Returns the font size of the node in logical pixels, used to resolve [`Units::Em`] values.
+    ///
+    /// If not set the font size is inherited from the parent of the node, or defaults to 16 pixels for the root node.
+    fn font_size(&self, store: &Self::Store) -> Option<f32>;
+
+    /// Returns the layout properties which override the properties of the node, given the space available to it.
+    ///
+    /// This can be used to change the layout type, size, or visibility of a node when its parent becomes too small,
+    /// within a single layout. If the size of the parent is auto, the query contains its size before its children
+    /// are laid out.
+    fn breakpoint(&self, store: &Self::Store, query: &LayoutQuery) -> Option<Breakpoint>;
 }
 
 /// Helper trait used internally for converting layout properties into a direction-agnostic value.
 pub(crate) trait NodeExt: Node {
-    fn main(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        match parent_layout_type {
-            LayoutType::Row => self.width(store).unwrap_or(Units::Stretch(1.0)),
-            LayoutType::Column => self.height(store).unwrap_or(Units::Stretch(1.0)),
-        }
+    // Evaluates the breakpoint of the node. This calls the breakpoint callback, so it is evaluated once for each
+    // layout of the node and passed to the functions which depend on it.
+    fn resolved_breakpoint(&self, store: &Self::Store, ctx: &LayoutContext<Self::CacheKey>) -> Breakpoint {
+        self.breakpoint(store, &ctx.query).unwrap_or_default()
     }
 
-    fn min_main(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap_default(
-            store,
-            |store| self.min_width(store),
-            |store| self.min_height(store),
-            Units::Pixels(0.0),
-        )
+    fn resolved_layout_type(&self, store: &Self::Store, breakpoint: &Breakpoint) -> LayoutType {
+        breakpoint.layout_type.or_else(|| self.layout_type(store)).unwrap_or_default()
     }
 
-    fn max_main(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap_default(
-            store,
-            |store| self.max_width(store),
-            |store| self.max_height(store),
-            Units::Pixels(f32::MAX),
-        )
+    fn resolved_visibility(&self, store: &Self::Store, breakpoint: &Breakpoint) -> Visibility {
+        breakpoint.visibility.or_else(|| self.visibility(store)).unwrap_or_default()
     }
 
-    fn cross(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        match parent_layout_type {
-            LayoutType::Row => self.height(store).unwrap_or(Units::Stretch(1.0)),
-            LayoutType::Column => self.width(store).unwrap_or(Units::Stretch(1.0)),
-        }
+    fn main(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+        breakpoint: &Breakpoint,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap_default(
+                store,
+                |store| breakpoint.width.or_else(|| self.width(store)),
+                |store| breakpoint.height.or_else(|| self.height(store)),
+                Units::Stretch(1.0),
+            )
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn min_cross(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap_default(
-            store,
-            |store| self.min_height(store),
-            |store| self.min_width(store),
-            Units::Pixels(0.0),
-        )
+    fn min_main(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap_default(
+                store,
+                |store| self.min_width(store),
+                |store| self.min_height(store),
+                Units::Pixels(0.0),
+            )
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn max_cross(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap_default(
-            store,
-            |store| self.max_height(store),
-            |store| self.max_width(store),
-            Units::Pixels(f32::MAX),
-        )
+    fn max_main(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap_default(
+                store,
+                |store| self.max_width(store),
+                |store| self.max_height(store),
+                Units::Pixels(f32::MAX),
+            )
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn cross(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+        breakpoint: &Breakpoint,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap_default(
+                store,
+                |store| breakpoint.height.or_else(|| self.height(store)),
+                |store| breakpoint.width.or_else(|| self.width(store)),
+                Units::Stretch(1.0),
+            )
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn min_cross(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap_default(
+                store,
+                |store| self.min_height(store),
+                |store| self.min_width(store),
+                Units::Pixels(0.0),
+            )
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn max_cross(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap_default(
+                store,
+                |store| self.max_height(store),
+                |store| self.max_width(store),
+                Units::Pixels(f32::MAX),
+            )
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.left(store), |store| self.top(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.right(store), |store| self.bottom(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.top(store), |store| self.left(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.bottom(store), |store| self.right(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn child_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.child_left(store), |store| self.child_top(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn child_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.child_right(store), |store| self.child_bottom(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn child_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.child_top(store), |store| self.child_left(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn child_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.child_bottom(store), |store| self.child_right(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn main_between(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.col_between(store), |store| self.row_between(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn cross_between(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.row_between(store), |store| self.col_between(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn main_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.left(store), |store| self.top(store))
+    fn min_child_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_child_left(store), |store| self.min_child_top(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn main_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.right(store), |store| self.bottom(store))
+    fn max_child_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_child_left(store), |store| self.max_child_top(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn cross_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.top(store), |store| self.left(store))
+    fn min_child_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_child_right(store), |store| self.min_child_bottom(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn max_child_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_child_right(store), |store| self.max_child_bottom(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn min_child_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_child_top(store), |store| self.min_child_left(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn max_child_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_child_top(store), |store| self.max_child_left(store))
+            .resolve(ctx, self.font_size(store))
+    }
+
+    fn min_child_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_child_bottom(store), |store| self.min_child_right(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn cross_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.bottom(store), |store| self.right(store))
+    fn max_child_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_child_bottom(store), |store| self.max_child_right(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn child_main_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.child_left(store), |store| self.child_top(store))
+    fn min_main_between(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_col_between(store), |store| self.min_row_between(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn child_main_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.child_right(store), |store| self.child_bottom(store))
+    fn max_main_between(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_col_between(store), |store| self.max_row_between(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn child_cross_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.child_top(store), |store| self.child_left(store))
+    fn min_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_left(store), |store| self.min_top(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn child_cross_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.child_bottom(store), |store| self.child_right(store))
+    fn max_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_left(store), |store| self.max_top(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn main_between(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.col_between(store), |store| self.row_between(store))
+    fn min_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_right(store), |store| self.min_bottom(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    // Currently unused until wrapping is implemented
-    #[allow(dead_code)]
-    fn cross_between(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.row_between(store), |store| self.col_between(store))
+    fn max_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_right(store), |store| self.max_bottom(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn min_main_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.min_left(store), |store| self.min_top(store))
+    fn min_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_top(store), |store| self.min_left(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn max_main_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.max_left(store), |store| self.max_top(store))
+    fn max_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_top(store), |store| self.max_left(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn min_main_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.min_right(store), |store| self.min_bottom(store))
+    fn min_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.min_bottom(store), |store| self.min_right(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn max_main_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.max_right(store), |store| self.max_bottom(store))
+    fn max_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.max_bottom(store), |store| self.max_right(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn min_cross_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.min_top(store), |store| self.min_left(store))
+    fn border_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.border_left(store), |store| self.border_top(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn max_cross_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.max_top(store), |store| self.max_left(store))
+    fn border_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.border_right(store), |store| self.border_bottom(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn min_cross_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.min_bottom(store), |store| self.min_right(store))
+    fn border_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.border_top(store), |store| self.border_left(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn max_cross_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.max_bottom(store), |store| self.max_right(store))
+    fn border_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.border_bottom(store), |store| self.border_right(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn border_main_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.border_left(store), |store| self.border_top(store))
+    fn padding_main_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.padding_left(store), |store| self.padding_top(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn border_main_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.border_right(store), |store| self.border_bottom(store))
+    fn padding_main_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.padding_right(store), |store| self.padding_bottom(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn border_cross_before(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.border_top(store), |store| self.border_left(store))
+    fn padding_cross_before(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.padding_top(store), |store| self.padding_left(store))
+            .resolve(ctx, self.font_size(store))
     }
 
-    fn border_cross_after(&self, store: &Self::Store, parent_layout_type: LayoutType) -> Units {
-        parent_layout_type.select_unwrap(store, |store| self.border_bottom(store), |store| self.border_right(store))
+    fn padding_cross_after(
+        &self,
+        store: &Self::Store,
+        parent_layout_type: LayoutType,
+        ctx: &LayoutContext<Self::CacheKey>,
+    ) -> Units {
+        parent_layout_type
+            .select_unwrap(store, |store| self.padding_bottom(store), |store| self.padding_right(store))
+            .resolve(ctx, self.font_size(store))
     }
 
     fn content_sizing(
@@ -327,12 +915,10 @@ pub(crate) trait NodeExt: Node {
         parent_main: Option<f32>,
         parent_cross: Option<f32>,
     ) -> Option<(f32, f32)> {
-        match parent_layout_type {
-            LayoutType::Row => self.content_size(store, sublayout, parent_main, parent_cross),
-
-            LayoutType::Column => {
-                self.content_size(store, sublayout, parent_cross, parent_main).map(|(width, height)| (height, width))
-            }
+        if parent_layout_type.is_row() {
+            self.content_size(store, sublayout, parent_main, parent_cross)
+        } else {
+            self.content_size(store, sublayout, parent_cross, parent_main).map(|(width, height)| (height, width))
         }
     }
 }
@@ -1,3 +1,6 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
 /// The layout type determines how the nodes will position its parent-directed children.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LayoutType {
@@ -6,9 +9,35 @@ pub enum LayoutType {
     /// Stack child elements vertically.
     #[default]
     Column,
+    /// Flow parent-directed child elements into columns of balanced height, filling each column from top to bottom.
+    ///
+    /// The number of columns is the [column count](crate::Node::column_count) of the node, or as many columns of the
+    /// [minimum column width](crate::Node::min_column_width) as fit. The columns are separated by the `col_between`
+    /// space and the children within a column by the `row_between` space of the node. A node with an auto width is
+    /// sized to fit its columns, each as wide as its widest child.
+    MultiColumn,
+    /// Arrange child elements around the centre of the content box, at even angles along an arc.
+    ///
+    /// The arc starts at the [start angle](crate::Node::start_angle) of the node and covers its
+    /// [angular span](crate::Node::angular_span), with the centres of the children at its
+    /// [radius](crate::Node::radius) from the centre.
+    Radial,
+    /// Attach child elements to the edges of the node in order, each taking space from the remaining area, with
+    /// the last child filling the area which is left.
+    ///
+    /// The edge of each child is determined by its [dock](crate::Node::dock) side, and each docked child is separated
+    /// from the remaining area by the space between rows or columns of the node. A node with an auto size is sized to
+    /// fit the children docked to its edges along with the last child.
+    Dock,
 }
 
 impl LayoutType {
+    /// Returns whether the main-axis of the children is horizontal, which is only the case for a
+    /// [`Row`](LayoutType::Row). The children of all other layout types have a vertical main-axis.
+    pub fn is_row(&self) -> bool {
+        *self == LayoutType::Row
+    }
+
     // Helper function for selecting between optional values depending on the layout type.
     pub(crate) fn select_unwrap<T: Default, S>(
         &self,
@@ -16,9 +45,10 @@ impl LayoutType {
         first: impl FnOnce(S) -> Option<T>,
         second: impl FnOnce(S) -> Option<T>,
     ) -> T {
-        match self {
-            LayoutType::Row => first(s).unwrap_or_default(),
-            LayoutType::Column => second(s).unwrap_or_default(),
+        if self.is_row() {
+            first(s).unwrap_or_default()
+        } else {
+            second(s).unwrap_or_default()
         }
     }
 
@@ -30,13 +60,28 @@ impl LayoutType {
         second: impl FnOnce(S) -> Option<T>,
         default: T,
     ) -> T {
-        match self {
-            LayoutType::Row => first(s).unwrap_or(default),
-            LayoutType::Column => second(s).unwrap_or(default),
+        if self.is_row() {
+            first(s).unwrap_or(default)
+        } else {
+            second(s).unwrap_or(default)
         }
     }
 }
 
+/// The edge of a [dock](crate::LayoutType::Dock) node to which a child is attached.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dock {
+    /// Attach the node to the left edge of the remaining area.
+    #[default]
+    Left,
+    /// Attach the node to the top edge of the remaining area.
+    Top,
+    /// Attach the node to the right edge of the remaining area.
+    Right,
+    /// Attach the node to the bottom edge of the remaining area.
+    Bottom,
+}
+
 /// The position type determines whether a node will be positioned in-line with its siblings or out-of-line / independently of its siblings.
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PositionType {
@@ -45,6 +90,133 @@ pub enum PositionType {
     /// Node is positioned relative to parent and in-line with siblings.
     #[default]
     ParentDirected,
+    /// Node is positioned in-line with siblings, then moved to stay within the visible region of the nearest
+    /// [scrolling](crate::Overflow::Scroll) ancestor, while remaining within the bounds of its parent.
+    Sticky,
+}
+
+/// The alignment of the children of a node along its main axis.
+///
+/// The alignment overrides the child-space of the node on the sides where the free space is distributed, which
+/// applies to children with `Auto` space on those sides.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MainAlignment {
+    /// Children are placed at the start of the main axis.
+    #[default]
+    Start,
+    /// Children are placed in the center of the main axis.
+    Center,
+    /// Children are placed at the end of the main axis.
+    End,
+    /// Free space is distributed evenly between the children.
+    SpaceBetween,
+    /// Free space is distributed evenly between the children and before the first and after the last child.
+    SpaceEvenly,
+}
+
+impl MainAlignment {
+    // Helper function for applying the alignment to the child-space before, between, and after the children.
+    pub(crate) fn apply(&self, before: Units, between: Units, after: Units) -> (Units, Units, Units) {
+        match self {
+            MainAlignment::Start => (before.without_stretch(), between, after.without_stretch()),
+            MainAlignment::Center => (Units::Stretch(1.0), between, Units::Stretch(1.0)),
+            MainAlignment::End => (Units::Stretch(1.0), between, after.without_stretch()),
+            MainAlignment::SpaceBetween => (before.without_stretch(), Units::Stretch(1.0), after.without_stretch()),
+            MainAlignment::SpaceEvenly => (Units::Stretch(1.0), Units::Stretch(1.0), Units::Stretch(1.0)),
+        }
+    }
+}
+
+/// The alignment of the children of a node along its cross axis.
+///
+/// The alignment overrides the child-space of the node on the sides where the free space is distributed, which
+/// applies to children with `Auto` space on those sides.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CrossAlignment {
+    /// Children are placed at the start of the cross axis.
+    #[default]
+    Start,
+    /// Children are placed in the center of the cross axis.
+    Center,
+    /// Children are placed at the end of the cross axis.
+    End,
+}
+
+impl CrossAlignment {
+    // Helper function for applying the alignment to the child-space before and after the children.
+    pub(crate) fn apply(&self, before: Units, after: Units) -> (Units, Units) {
+        match self {
+            CrossAlignment::Start => (before.without_stretch(), after.without_stretch()),
+            CrossAlignment::Center => (Units::Stretch(1.0), Units::Stretch(1.0)),
+            CrossAlignment::End => (Units::Stretch(1.0), after.without_stretch()),
+        }
+    }
+}
+
+/// The box sizing determines whether the fixed size of a node includes its border and padding.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoxSizing {
+    /// The size and size constraints of the node include its border and padding.
+    #[default]
+    BorderBox,
+    /// The size and size constraints of the node apply to its content box, with the border and padding added on.
+    ContentBox,
+}
+
+/// The content size mode determines how the content size of a node is combined with its children.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentSizeMode {
+    /// The content size is only used when the node has no parent-directed children.
+    #[default]
+    Exclusive,
+    /// The auto size of the node is the maximum of the content size and the extent of its children,
+    /// with the children overlapping the content.
+    Max,
+    /// The content is treated as an implicit first child, with the children positioned after it.
+    FirstChild,
+}
+
+/// The overflow determines how a node handles parent-directed children which do not fit within it on the main-axis.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// Children which do not fit extend past the edges of the node.
+    #[default]
+    Visible,
+    /// Children are collapsed in order of their overflow priority until the remaining children fit within the node.
+    Collapse,
+    /// The main-axis of the node is unconstrained, and the children are translated by the scroll offset of the node.
+    ///
+    /// Stretch space and size of the children share the space of the visible region, which is the content box of the
+    /// node, that is left by the other children. When the children overflow the visible region there is no space
+    /// left, so stretch space and size take their minimum. A child with the default stretch size fills the visible
+    /// region unless its siblings overflow it, in which case it collapses to its minimum size.
+    Scroll,
+}
+
+/// The uniform children determine whether the parent-directed children of a node are given the same size.
+///
+/// Children with a stretch size on an axis are not given the uniform size on that axis.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UniformChildren {
+    /// Each child has its own size.
+    #[default]
+    Disabled,
+    /// Each child is given the largest main-axis size of the children, within its own size constraints.
+    Main,
+    /// Each child is given the largest main-axis and cross-axis sizes of the children, within its own size constraints.
+    MainAndCross,
+}
+
+/// The visibility determines whether a node is shown and whether it takes part in layout.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Visibility {
+    /// Node is shown and takes part in layout.
+    #[default]
+    Visible,
+    /// Node is not shown but is still sized and positioned, taking up space within its parent.
+    Hidden,
+    /// Node is not shown and is removed from layout, taking up no space within its parent.
+    Collapsed,
 }
 
 /// Units which describe spacing and size.
@@ -57,6 +229,30 @@ pub enum Units {
     /// A percentage of the parent's width when applied to left, width, right properties.
     /// A percentage of the parent's height when applied to top, height, bottom properties.
     Percentage(f32),
+    /// A number of logical pixels combined with a percentage of the parent dimension.
+    ///
+    /// For example, `Calc { px: -48.0, percent: 100.0 }` resolves to the parent dimension minus 48 pixels.
+    Calc {
+        /// The number of logical pixels.
+        px: f32,
+        /// The percentage of the parent dimension.
+        percent: f32,
+    },
+    /// A percentage of the width of the root node.
+    ///
+    /// The root node is the node on which [`layout`](crate::Node::layout) is called.
+    ViewportWidth(f32),
+    /// A percentage of the height of the root node.
+    ///
+    /// The root node is the node on which [`layout`](crate::Node::layout) is called.
+    ViewportHeight(f32),
+    /// A multiple of the font size of the node.
+    ///
+    /// The font size is provided by the [`font_size`](crate::Node::font_size) property of the node, or is
+    /// inherited from the parent of the node if not set.
+    Em(f32),
+    /// A multiple of the font size of the root node.
+    Rem(f32),
     /// A factor of the remaining free space.
     ///
     /// The remaining free space is the parent space minus the space and size of any fixed-size nodes in that axis.
@@ -78,10 +274,19 @@ pub enum Units {
 
 impl Units {
     /// Returns the units converted to pixels or a provided default.
+    ///
+    /// Percentages, and the percentage part of [`Calc`](Units::Calc) units, are relative to the `parent_value`.
+    /// [`Stretch`](Units::Stretch) and [`Auto`](Units::Auto) units return the provided default.
+    ///
+    /// [`ViewportWidth`](Units::ViewportWidth), [`ViewportHeight`](Units::ViewportHeight), [`Em`](Units::Em) and
+    /// [`Rem`](Units::Rem) units depend on the size of the root node and on font sizes, which are only known during
+    /// layout, so they also return the provided default. Layout converts them to pixels before calling this method.
     pub fn to_px(&self, parent_value: f32, default: f32) -> f32 {
         match self {
             Units::Pixels(pixels) => *pixels,
             Units::Percentage(percentage) => (percentage / 100.0) * parent_value,
+            Units::Calc { px, percent } => px + (percent / 100.0) * parent_value,
+            Units::ViewportWidth(_) | Units::ViewportHeight(_) | Units::Em(_) | Units::Rem(_) => default,
             Units::Stretch(_) => default,
             Units::Auto => default,
         }
@@ -94,6 +299,10 @@ impl Units {
         match self {
             Units::Pixels(pixels) => pixels.min(max).max(min),
             Units::Percentage(percentage) => ((percentage / 100.0) * parent_value).min(max).max(min),
+            Units::Calc { px, percent } => (px + (percent / 100.0) * parent_value).min(max).max(min),
+            Units::ViewportWidth(_) | Units::ViewportHeight(_) | Units::Em(_) | Units::Rem(_) => {
+                default.min(max).max(min)
+            }
             Units::Stretch(_) => default.min(max).max(min),
             Units::Auto => default.min(max).max(min),
         }
@@ -120,6 +329,21 @@ impl Units {
         matches!(self, Units::Percentage(_))
     }
 
+    /// Returns true if the value is a combination of pixels and a percentage.
+    pub fn is_calc(&self) -> bool {
+        matches!(self, Units::Calc { .. })
+    }
+
+    /// Returns true if the value is relative to the size of the root node.
+    pub fn is_viewport(&self) -> bool {
+        matches!(self, Units::ViewportWidth(_) | Units::ViewportHeight(_))
+    }
+
+    /// Returns true if the value is relative to a font size.
+    pub fn is_font_relative(&self) -> bool {
+        matches!(self, Units::Em(_) | Units::Rem(_))
+    }
+
     /// Returns true if the value is a stretch factor.
     pub fn is_stretch(&self) -> bool {
         matches!(self, Units::Stretch(_))
@@ -129,6 +353,119 @@ impl Units {
     pub fn is_auto(&self) -> bool {
         self == &Units::Auto
     }
+
+    // Helper function for determining whether the value depends on a percentage of the parent size.
+    pub(crate) fn is_percentage_based(&self) -> bool {
+        self.is_percentage() || self.is_calc()
+    }
+
+    // Helper function for replacing stretch units with zero pixels.
+    pub(crate) fn without_stretch(self) -> Self {
+        if self.is_stretch() {
+            Units::Pixels(0.0)
+        } else {
+            self
+        }
+    }
+
+    // Helper function for converting units which are relative to the root node or a font size into pixels.
+    // The `font_size` is the font size of the node the units belong to, if it has been set.
+    pub(crate) fn resolve<K>(self, ctx: &LayoutContext<K>, font_size: Option<f32>) -> Self {
+        match self {
+            Units::ViewportWidth(val) => Units::Pixels((val / 100.0) * ctx.root_width),
+            Units::ViewportHeight(val) => Units::Pixels((val / 100.0) * ctx.root_height),
+            Units::Em(val) => Units::Pixels(val * font_size.unwrap_or(ctx.font_size)),
+            Units::Rem(val) => Units::Pixels(val * ctx.root_font_size),
+            _ => self,
+        }
+    }
+}
+
+/// The shared size group of each measured node, keyed by the node, with its width and height on its auto-sized axes.
+pub(crate) type MeasuredSizes<K> = RefCell<HashMap<K, (u32, f32, f32)>>;
+
+/// The state passed down the tree during [`layout`](crate::Node::layout), keyed by the [`CacheKey`](crate::Node::CacheKey)
+/// of the nodes.
+///
+/// This holds the values used to resolve the units of a node, the constraints given to it by its parent, and the state
+/// shared by every node of a single layout, such as the sizes measured for shared size groups.
+pub(crate) struct LayoutContext<'a, K> {
+    /// The width of the root node.
+    pub root_width: f32,
+    /// The height of the root node.
+    pub root_height: f32,
+    /// The font size of the root node.
+    pub root_font_size: f32,
+    /// The font size inherited by nodes which do not specify a font size.
+    pub font_size: f32,
+    /// The size of the content box of the parent on the main axis of the parent, used to resolve percentages.
+    pub parent_main: f32,
+    /// The size of the content box of the parent on the cross axis of the parent, used to resolve percentages.
+    pub parent_cross: f32,
+    /// Whether the size of the content box of the parent on its main axis is not yet known,
+    /// in which case percentage sizes are treated as auto.
+    pub indefinite_main: bool,
+    /// Whether the size of the content box of the parent on its cross axis is not yet known,
+    /// in which case percentage sizes are treated as auto.
+    pub indefinite_cross: bool,
+    /// The query used to evaluate the breakpoints of the children of the parent.
+    pub query: LayoutQuery,
+    /// The width and height of the largest auto-sized nodes in each shared size group, keyed by the identifier of the
+    /// group, as measured by a previous layout pass.
+    pub size_groups: &'a HashMap<u32, (f32, f32)>,
+    /// The shared size group of each node in a shared size group, with its width and height on the axes on which it is
+    /// auto-sized, as measured by the latest layout of the node. Only measured by the first layout pass.
+    pub measured_sizes: Option<&'a MeasuredSizes<K>>,
+    /// The size of the node on the main axis of the parent, given to it by a parent with uniform children.
+    pub uniform_main: Option<f32>,
+    /// The size of the node on the cross axis of the parent, given to it by a parent with uniform children.
+    pub uniform_cross: Option<f32>,
+    /// The breakpoint of the node, if it has already been evaluated by its parent.
+    pub breakpoint: Option<Breakpoint>,
+    /// The nodes which were skipped by the latest layout of their parents, because they are collapsed or overflowed.
+    pub skipped: &'a RefCell<HashSet<K>>,
+}
+
+// Implemented manually as the context only holds references to the keys, so it can be copied whatever the key type.
+impl<K> Clone for LayoutContext<'_, K> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<K> Copy for LayoutContext<'_, K> {}
+
+/// The space available to a node during layout, used to evaluate its [`breakpoint`](crate::Node::breakpoint).
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct LayoutQuery {
+    /// The width of the content box of the parent of the node.
+    pub parent_width: f32,
+    /// The height of the content box of the parent of the node.
+    pub parent_height: f32,
+}
+
+/// Layout properties which override the properties of a node depending on the space available to it.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct Breakpoint {
+    /// Overrides the layout type of the node.
+    pub layout_type: Option<LayoutType>,
+    /// Overrides the width of the node.
+    pub width: Option<Units>,
+    /// Overrides the height of the node.
+    pub height: Option<Units>,
+    /// Overrides the visibility of the node.
+    pub visibility: Option<Visibility>,
+}
+
+/// The items of a virtual list, in which only the children within the visible window of the node are laid out.
+#[derive(Default, Debug, Copy, Clone, PartialEq)]
+pub struct VirtualList {
+    /// The number of items in the list.
+    pub count: usize,
+    /// The extent of each item on the main-axis of the list.
+    pub item_extent: f32,
+    /// The number of items laid out before and after the visible window.
+    pub overscan: usize,
 }
 
 /// A type which represents the computed size of a node after [`layout`](crate::Node::layout).
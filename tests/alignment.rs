@@ -0,0 +1,186 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+fn three_children(world: &mut World, root: Entity) -> (Entity, Entity, Entity) {
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(100.0));
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(100.0));
+    world.set_height(node3, Units::Pixels(100.0));
+
+    (node1, node2, node3)
+}
+
+#[test]
+fn main_alignment_center() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_main_alignment(root, MainAlignment::Center);
+
+    let (node1, node2, node3) = three_children(&mut world, root);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 150.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 250.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 350.0, width: 100.0, height: 100.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 150.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 250.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 350.0, posy: 0.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn main_alignment_end() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_main_alignment(root, MainAlignment::End);
+    world.set_child_bottom(root, Units::Pixels(20.0));
+
+    let (node1, node2, node3) = three_children(&mut world, root);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 280.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 380.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 480.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn main_alignment_space_between() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_main_alignment(root, MainAlignment::SpaceBetween);
+
+    let (node1, node2, node3) = three_children(&mut world, root);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 250.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 500.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn main_alignment_space_evenly() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_main_alignment(root, MainAlignment::SpaceEvenly);
+
+    let (node1, node2, node3) = three_children(&mut world, root);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 75.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 250.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 425.0, posy: 0.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn main_alignment_start_overrides_stretch_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_child_space(root, Units::Stretch(1.0));
+    world.set_main_alignment(root, MainAlignment::Start);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 250.0, posy: 0.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn cross_alignment() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_cross_alignment(root, CrossAlignment::Center);
+
+    let (node1, node2, node3) = three_children(&mut world, root);
+    world.set_align_self(node2, CrossAlignment::End);
+    world.set_align_self(node3, CrossAlignment::Start);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 250.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 500.0, posy: 100.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 200.0, width: 100.0, height: 100.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 250.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 100.0, posy: 500.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 200.0, posy: 0.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn cross_alignment_explicit_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_cross_alignment(root, CrossAlignment::End);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_left(node, Units::Pixels(20.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 20.0, posy: 0.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn alignment_self_directed() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_main_alignment(root, MainAlignment::Center);
+    world.set_cross_alignment(root, CrossAlignment::End);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_position_type(node, PositionType::SelfDirected);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 500.0, posy: 250.0, width: 100.0, height: 100.0 }));
+}
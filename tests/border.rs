@@ -88,3 +88,68 @@ fn border_parent_auto() {
 
     assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 30.0, height: 30.0 }));
 }
+
+#[test]
+fn border_pixels_percentage_child() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_border(root, Units::Pixels(50.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Percentage(50.0));
+    world.set_height(node, Units::Percentage(50.0));
+    world.set_left(node, Units::Percentage(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 100.0, posy: 50.0, width: 250.0, height: 250.0 }));
+}
+
+#[test]
+fn border_percentage_of_parent() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_border(node, Units::Percentage(5.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 30.0, posy: 30.0, width: 140.0, height: 140.0 }));
+}
+
+#[test]
+fn border_percentage_stretch_node() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Stretch(1.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_border(node, Units::Percentage(5.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 600.0, height: 200.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 30.0, posy: 30.0, width: 540.0, height: 140.0 }));
+}
@@ -0,0 +1,149 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn border_box_pixels() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_border(node, Units::Pixels(10.0));
+    world.set_padding(node, Units::Pixels(5.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 15.0, posy: 15.0, width: 70.0, height: 70.0 }));
+}
+
+#[test]
+fn content_box_pixels() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_border(node, Units::Pixels(10.0));
+    world.set_padding(node, Units::Pixels(5.0));
+    world.set_box_sizing(node, BoxSizing::ContentBox);
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 130.0, height: 130.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 15.0, posy: 15.0, width: 100.0, height: 100.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_layout_type(node, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 130.0, height: 130.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 15.0, posy: 15.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn content_box_percentage() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Percentage(50.0));
+    world.set_height(node, Units::Percentage(25.0));
+    world.set_padding(node, Units::Pixels(10.0));
+    world.set_box_sizing(node, BoxSizing::ContentBox);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 320.0, height: 170.0 }));
+}
+
+#[test]
+fn content_box_size_constraints() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(50.0));
+    world.set_min_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_max_height(node, Units::Pixels(50.0));
+    world.set_padding(node, Units::Pixels(10.0));
+    world.set_box_sizing(node, BoxSizing::ContentBox);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 120.0, height: 70.0 }));
+}
+
+#[test]
+fn content_box_auto() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_border(node, Units::Pixels(5.0));
+    world.set_padding(node, Units::Pixels(10.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(50.0));
+    world.set_height(child, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 80.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 15.0, posy: 15.0, width: 50.0, height: 50.0 }));
+
+    world.set_box_sizing(node, BoxSizing::ContentBox);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 80.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 15.0, posy: 15.0, width: 50.0, height: 50.0 }));
+}
+
+#[test]
+fn content_size_with_padding() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(120.0));
+    world.set_height(node, Units::Auto);
+    world.set_padding(node, Units::Pixels(10.0));
+    world.set_content_size(node, |_, width, _| (width.unwrap(), width.unwrap() / 2.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 120.0, height: 70.0 }));
+}
@@ -0,0 +1,146 @@
+use std::{cell::Cell, rc::Rc};
+
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn breakpoint_layout_type_and_visibility() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let sidebar = world.add(Some(root));
+    world.set_width(sidebar, Units::Stretch(1.0));
+    world.set_height(sidebar, Units::Auto);
+    world.set_layout_type(sidebar, LayoutType::Row);
+    world.set_breakpoint(sidebar, |query| {
+        (query.parent_width < 400.0)
+            .then_some(Breakpoint { layout_type: Some(LayoutType::Column), ..Default::default() })
+    });
+
+    let icon = world.add(Some(sidebar));
+    world.set_width(icon, Units::Pixels(50.0));
+    world.set_height(icon, Units::Pixels(50.0));
+
+    let label = world.add(Some(sidebar));
+    world.set_width(label, Units::Pixels(100.0));
+    world.set_height(label, Units::Pixels(50.0));
+    world.set_breakpoint(label, |query| {
+        (query.parent_width < 400.0)
+            .then_some(Breakpoint { visibility: Some(Visibility::Collapsed), ..Default::default() })
+    });
+
+    let icon2 = world.add(Some(sidebar));
+    world.set_width(icon2, Units::Pixels(50.0));
+    world.set_height(icon2, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(sidebar), Some(&Rect { posx: 0.0, posy: 0.0, width: 600.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(label), Some(&Rect { posx: 50.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(icon2), Some(&Rect { posx: 150.0, posy: 0.0, width: 50.0, height: 50.0 }));
+
+    world.set_width(root, Units::Pixels(300.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(sidebar), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(label), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(icon2), Some(&Rect { posx: 0.0, posy: 50.0, width: 50.0, height: 50.0 }));
+}
+
+#[test]
+fn breakpoint_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(500.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_breakpoint(node, |query| {
+        (query.parent_width < 500.0).then_some(Breakpoint {
+            width: Some(Units::Stretch(1.0)),
+            height: Some(Units::Pixels(200.0)),
+            ..Default::default()
+        })
+    });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 500.0, height: 100.0 }));
+
+    world.set_width(root, Units::Pixels(400.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 400.0, height: 200.0 }));
+
+    world.set_layout_type(root, LayoutType::Column);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 400.0, height: 200.0 }));
+}
+
+#[test]
+fn breakpoint_query_content_box() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(450.0));
+    world.set_height(node, Units::Pixels(300.0));
+    world.set_padding(node, Units::Pixels(50.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(100.0));
+    world.set_height(child, Units::Pixels(100.0));
+    world.set_breakpoint(child, |query| {
+        (query.parent_width < 400.0 && query.parent_height == 200.0)
+            .then_some(Breakpoint { width: Some(Units::Pixels(50.0)), ..Default::default() })
+    });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 50.0, posy: 50.0, width: 50.0, height: 100.0 }));
+}
+
+#[test]
+fn breakpoint_evaluated_once() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Stretch(1.0));
+    world.set_height(node, Units::Auto);
+
+    let count = Rc::new(Cell::new(0));
+    let counter = count.clone();
+    world.set_breakpoint(node, move |_| {
+        counter.set(counter.get() + 1);
+        None
+    });
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(count.get(), 1);
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 600.0, height: 50.0 }));
+}
@@ -0,0 +1,111 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn calc_width_beside_pixels_sidebar() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let sidebar = world.add(Some(root));
+    world.set_width(sidebar, Units::Pixels(48.0));
+    world.set_height(sidebar, Units::Stretch(1.0));
+
+    let content = world.add(Some(root));
+    world.set_width(content, Units::Calc { px: -48.0, percent: 100.0 });
+    world.set_height(content, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(sidebar), Some(&Rect { posx: 0.0, posy: 0.0, width: 48.0, height: 600.0 }));
+    assert_eq!(world.cache.bounds(content), Some(&Rect { posx: 48.0, posy: 0.0, width: 552.0, height: 600.0 }));
+}
+
+#[test]
+fn calc_width_calc_height() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Calc { px: 10.0, percent: 50.0 });
+    world.set_height(node, Units::Calc { px: -100.0, percent: 25.0 });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 310.0, height: 50.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 310.0, height: 50.0 }));
+}
+
+#[test]
+fn calc_min_width_max_height() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_min_width(node, Units::Calc { px: 20.0, percent: 50.0 });
+    world.set_height(node, Units::Stretch(1.0));
+    world.set_max_height(node, Units::Calc { px: -100.0, percent: 50.0 });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 320.0, height: 200.0 }));
+}
+
+#[test]
+fn calc_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_left(node, Units::Calc { px: -100.0, percent: 50.0 });
+    world.set_top(node, Units::Calc { px: 10.0, percent: 10.0 });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 200.0, posy: 70.0, width: 100.0, height: 100.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 200.0, posy: 70.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn calc_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_child_left(root, Units::Calc { px: 10.0, percent: 10.0 });
+    world.set_child_top(root, Units::Calc { px: -10.0, percent: 50.0 });
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 70.0, posy: 290.0, width: 100.0, height: 100.0 }));
+}
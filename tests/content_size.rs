@@ -1,3 +1,5 @@
+use std::{cell::Cell, rc::Rc};
+
 use morphorm::*;
 use morphorm_ecs::*;
 
@@ -350,3 +352,230 @@ fn equal_aspect_ratio() {
 
     assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 600.0, height: 600.0 }));
 }
+
+#[test]
+fn content_size_with_children_exclusive() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_content_size(node, |_, _, _| (100.0, 20.0));
+
+    let icon = world.add(Some(node));
+    world.set_width(icon, Units::Pixels(30.0));
+    world.set_height(icon, Units::Pixels(30.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 30.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 0.0, posy: 0.0, width: 30.0, height: 30.0 }));
+}
+
+#[test]
+fn content_size_with_children_max() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_content_size(node, |_, _, _| (100.0, 20.0));
+    world.set_content_size_mode(node, ContentSizeMode::Max);
+
+    let icon = world.add(Some(node));
+    world.set_width(icon, Units::Pixels(30.0));
+    world.set_height(icon, Units::Pixels(30.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 0.0, posy: 0.0, width: 30.0, height: 30.0 }));
+
+    world.set_layout_type(node, LayoutType::Column);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 0.0, posy: 0.0, width: 30.0, height: 30.0 }));
+}
+
+#[test]
+fn content_size_with_children_first_child() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_content_size(node, |_, _, _| (100.0, 20.0));
+    world.set_content_size_mode(node, ContentSizeMode::FirstChild);
+
+    let icon = world.add(Some(node));
+    world.set_width(icon, Units::Pixels(30.0));
+    world.set_height(icon, Units::Pixels(30.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 130.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 100.0, posy: 0.0, width: 30.0, height: 30.0 }));
+
+    world.set_layout_type(node, LayoutType::Column);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 0.0, posy: 20.0, width: 30.0, height: 30.0 }));
+}
+
+#[test]
+fn content_size_with_children_max_stretch_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(300.0));
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_content_size(node, |_, width, _| (width.unwrap(), 20.0));
+    world.set_content_size_mode(node, ContentSizeMode::Max);
+
+    let icon = world.add(Some(node));
+    world.set_left(icon, Units::Stretch(1.0));
+    world.set_width(icon, Units::Pixels(30.0));
+    world.set_height(icon, Units::Pixels(30.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(icon), Some(&Rect { posx: 270.0, posy: 0.0, width: 30.0, height: 30.0 }));
+}
+
+#[test]
+fn content_size_stretch_height_parent_auto_height() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(450.0));
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Row);
+
+    let node1 = world.add(Some(node));
+    world.set_width(node1, Units::Stretch(1.0));
+    world.set_height(node1, Units::Auto);
+    world.set_content_size(node1, |_, width, _| (width.unwrap(), width.unwrap() / 2.0));
+
+    let node2 = world.add(Some(node));
+    world.set_width(node2, Units::Stretch(1.0));
+    world.set_height(node2, Units::Stretch(1.0));
+
+    let node3 = world.add(Some(node));
+    world.set_width(node3, Units::Pixels(50.0));
+    world.set_height(node3, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 450.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 200.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 400.0, posy: 0.0, width: 50.0, height: 100.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 450.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 200.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 400.0, posy: 0.0, width: 50.0, height: 100.0 }));
+}
+
+#[test]
+fn content_size_stretch_width_parent_auto_width() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let menu = world.add(Some(root));
+    world.set_width(menu, Units::Auto);
+    world.set_height(menu, Units::Pixels(200.0));
+    world.set_layout_type(menu, LayoutType::Column);
+
+    let item1 = world.add(Some(menu));
+    world.set_width(item1, Units::Auto);
+    world.set_height(item1, Units::Stretch(1.0));
+    world.set_content_size(item1, |_, _, height| (height.unwrap() * 2.0, height.unwrap()));
+
+    let item2 = world.add(Some(menu));
+    world.set_width(item2, Units::Stretch(1.0));
+    world.set_height(item2, Units::Pixels(20.0));
+
+    let item3 = world.add(Some(item2));
+    world.set_width(item3, Units::Stretch(1.0));
+    world.set_height(item3, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(menu), Some(&Rect { posx: 0.0, posy: 0.0, width: 360.0, height: 200.0 }));
+    assert_eq!(world.cache.bounds(item1), Some(&Rect { posx: 0.0, posy: 0.0, width: 360.0, height: 180.0 }));
+    assert_eq!(world.cache.bounds(item2), Some(&Rect { posx: 0.0, posy: 180.0, width: 360.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(item3), Some(&Rect { posx: 0.0, posy: 0.0, width: 360.0, height: 20.0 }));
+}
+
+#[test]
+fn content_size_nested_stretch_width_measured_once() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let mut parent = root;
+    for _ in 0..12 {
+        let node = world.add(Some(parent));
+        world.set_width(node, Units::Stretch(1.0));
+        world.set_min_width(node, Units::Auto);
+        world.set_height(node, Units::Auto);
+        parent = node;
+    }
+
+    let count = Rc::new(Cell::new(0));
+    let counter = count.clone();
+    let leaf = world.add(Some(parent));
+    world.set_width(leaf, Units::Auto);
+    world.set_height(leaf, Units::Auto);
+    world.set_content_size(leaf, move |_, _, _| {
+        counter.set(counter.get() + 1);
+        (50.0, 20.0)
+    });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(count.get(), 1);
+    assert_eq!(world.cache.bounds(parent), Some(&Rect { posx: 0.0, posy: 0.0, width: 600.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(leaf), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 20.0 }));
+}
@@ -0,0 +1,245 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn dock_editor_shell() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(300.0));
+    world.set_layout_type(root, LayoutType::Dock);
+
+    let toolbar = world.add(Some(root));
+    world.set_height(toolbar, Units::Pixels(30.0));
+    world.set_dock(toolbar, Dock::Top);
+
+    let status_bar = world.add(Some(root));
+    world.set_height(status_bar, Units::Pixels(20.0));
+    world.set_dock(status_bar, Dock::Bottom);
+
+    let tree = world.add(Some(root));
+    world.set_width(tree, Units::Pixels(100.0));
+    world.set_dock(tree, Dock::Left);
+
+    let inspector = world.add(Some(root));
+    world.set_width(inspector, Units::Pixels(80.0));
+    world.set_dock(inspector, Dock::Right);
+
+    let document = world.add(Some(root));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(toolbar), Some(&Rect { posx: 0.0, posy: 0.0, width: 400.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(status_bar), Some(&Rect { posx: 0.0, posy: 280.0, width: 400.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(tree), Some(&Rect { posx: 0.0, posy: 30.0, width: 100.0, height: 250.0 }));
+    assert_eq!(world.cache.bounds(inspector), Some(&Rect { posx: 320.0, posy: 30.0, width: 80.0, height: 250.0 }));
+    assert_eq!(world.cache.bounds(document), Some(&Rect { posx: 100.0, posy: 30.0, width: 220.0, height: 250.0 }));
+}
+
+#[test]
+fn dock_last_child_fills() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(300.0));
+    world.set_layout_type(root, LayoutType::Dock);
+
+    let node1 = world.add(Some(root));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_dock(node1, Dock::Bottom);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_dock(node2, Dock::Right);
+
+    let node3 = world.add(Some(root));
+    world.set_dock(node3, Dock::Top);
+    world.set_visibility(node3, Visibility::Collapsed);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 250.0, width: 400.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 250.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}
+
+#[test]
+fn dock_padding() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(300.0));
+    world.set_padding_left(root, Units::Pixels(10.0));
+    world.set_padding_top(root, Units::Pixels(10.0));
+    world.set_padding_right(root, Units::Pixels(10.0));
+    world.set_padding_bottom(root, Units::Pixels(10.0));
+    world.set_layout_type(root, LayoutType::Dock);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Percentage(25.0));
+    world.set_dock(node1, Dock::Left);
+
+    let node2 = world.add(Some(root));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 10.0, posy: 10.0, width: 95.0, height: 280.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 105.0, posy: 10.0, width: 285.0, height: 280.0 }));
+}
+
+#[test]
+fn dock_auto_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Dock);
+
+    let toolbar = world.add(Some(node));
+    world.set_height(toolbar, Units::Pixels(30.0));
+    world.set_dock(toolbar, Dock::Top);
+
+    let tree = world.add(Some(node));
+    world.set_width(tree, Units::Pixels(100.0));
+    world.set_dock(tree, Dock::Left);
+
+    let document = world.add(Some(node));
+    world.set_width(document, Units::Pixels(200.0));
+    world.set_height(document, Units::Pixels(150.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 180.0 }));
+    assert_eq!(world.cache.bounds(toolbar), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(tree), Some(&Rect { posx: 0.0, posy: 30.0, width: 100.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(document), Some(&Rect { posx: 100.0, posy: 30.0, width: 200.0, height: 150.0 }));
+}
+
+#[test]
+fn dock_space_between() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(400.0));
+    world.set_height(node, Units::Pixels(300.0));
+    world.set_layout_type(node, LayoutType::Dock);
+    world.set_row_between(node, Units::Pixels(10.0));
+    world.set_col_between(node, Units::Pixels(20.0));
+
+    let toolbar = world.add(Some(node));
+    world.set_height(toolbar, Units::Pixels(30.0));
+    world.set_dock(toolbar, Dock::Top);
+
+    let status_bar = world.add(Some(node));
+    world.set_height(status_bar, Units::Pixels(20.0));
+    world.set_dock(status_bar, Dock::Bottom);
+
+    let tree = world.add(Some(node));
+    world.set_width(tree, Units::Pixels(100.0));
+    world.set_dock(tree, Dock::Left);
+
+    let inspector = world.add(Some(node));
+    world.set_width(inspector, Units::Pixels(80.0));
+    world.set_dock(inspector, Dock::Right);
+
+    let document = world.add(Some(node));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(toolbar), Some(&Rect { posx: 0.0, posy: 0.0, width: 400.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(status_bar), Some(&Rect { posx: 0.0, posy: 280.0, width: 400.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(tree), Some(&Rect { posx: 0.0, posy: 40.0, width: 100.0, height: 230.0 }));
+    assert_eq!(world.cache.bounds(inspector), Some(&Rect { posx: 320.0, posy: 40.0, width: 80.0, height: 230.0 }));
+    assert_eq!(world.cache.bounds(document), Some(&Rect { posx: 120.0, posy: 40.0, width: 180.0, height: 230.0 }));
+}
+
+#[test]
+fn dock_auto_size_space_between() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Dock);
+    world.set_row_between(node, Units::Pixels(10.0));
+    world.set_col_between(node, Units::Pixels(20.0));
+
+    let toolbar = world.add(Some(node));
+    world.set_height(toolbar, Units::Pixels(30.0));
+    world.set_dock(toolbar, Dock::Top);
+
+    let tree = world.add(Some(node));
+    world.set_width(tree, Units::Pixels(100.0));
+    world.set_dock(tree, Dock::Left);
+
+    let document = world.add(Some(node));
+    world.set_width(document, Units::Pixels(200.0));
+    world.set_height(document, Units::Pixels(150.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 320.0, height: 190.0 }));
+    assert_eq!(world.cache.bounds(toolbar), Some(&Rect { posx: 0.0, posy: 0.0, width: 320.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(tree), Some(&Rect { posx: 0.0, posy: 40.0, width: 100.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(document), Some(&Rect { posx: 120.0, posy: 40.0, width: 200.0, height: 150.0 }));
+}
+
+#[test]
+fn dock_auto_size_self_directed_contributes_to_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Dock);
+
+    let toolbar = world.add(Some(node));
+    world.set_height(toolbar, Units::Pixels(30.0));
+    world.set_dock(toolbar, Dock::Top);
+
+    let document = world.add(Some(node));
+    world.set_width(document, Units::Pixels(200.0));
+    world.set_height(document, Units::Pixels(150.0));
+
+    let badge = world.add(Some(node));
+    world.set_width(badge, Units::Pixels(40.0));
+    world.set_height(badge, Units::Pixels(40.0));
+    world.set_left(badge, Units::Pixels(260.0));
+    world.set_top(badge, Units::Pixels(200.0));
+    world.set_position_type(badge, PositionType::SelfDirected);
+    world.set_contributes_to_size(badge, true);
+
+    let ignored = world.add(Some(node));
+    world.set_width(ignored, Units::Pixels(40.0));
+    world.set_height(ignored, Units::Pixels(40.0));
+    world.set_left(ignored, Units::Pixels(500.0));
+    world.set_top(ignored, Units::Pixels(500.0));
+    world.set_position_type(ignored, PositionType::SelfDirected);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 240.0 }));
+    assert_eq!(world.cache.bounds(toolbar), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(document), Some(&Rect { posx: 0.0, posy: 30.0, width: 200.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 260.0, posy: 200.0, width: 40.0, height: 40.0 }));
+}
@@ -0,0 +1,110 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn em_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_font_size(node, 20.0);
+    world.set_width(node, Units::Em(5.0));
+    world.set_height(node, Units::Em(2.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 40.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 40.0 }));
+}
+
+#[test]
+fn em_default_font_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Em(2.0));
+    world.set_height(node, Units::Rem(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 32.0, height: 16.0 }));
+}
+
+#[test]
+fn em_inherited_font_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_font_size(root, 10.0);
+
+    let parent = world.add(Some(root));
+    world.set_width(parent, Units::Pixels(400.0));
+    world.set_height(parent, Units::Pixels(400.0));
+    world.set_font_size(parent, 20.0);
+
+    let node = world.add(Some(parent));
+    world.set_width(node, Units::Em(5.0));
+    world.set_height(node, Units::Rem(5.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn em_space_and_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let parent = world.add(Some(root));
+    world.set_width(parent, Units::Pixels(400.0));
+    world.set_height(parent, Units::Pixels(400.0));
+    world.set_font_size(parent, 10.0);
+    world.set_child_left(parent, Units::Em(3.0));
+
+    let node = world.add(Some(parent));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_font_size(node, 20.0);
+    world.set_top(node, Units::Em(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 30.0, posy: 20.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn em_border() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_font_size(root, 10.0);
+    world.set_border(root, Units::Em(5.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Stretch(1.0));
+    world.set_height(node, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 50.0, posy: 50.0, width: 500.0, height: 500.0 }));
+}
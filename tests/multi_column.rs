@@ -0,0 +1,382 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn multi_column_fixed_count() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(210.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let children: Vec<_> = (0..4).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 0.0, posy: 50.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 110.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[3]), Some(&Rect { posx: 110.0, posy: 50.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_balanced() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+
+    let first = world.add(Some(node));
+    world.set_width(first, Units::Stretch(1.0));
+    world.set_height(first, Units::Pixels(100.0));
+
+    let children: Vec<_> = (0..5).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(20.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(first), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(children[4]), Some(&Rect { posx: 100.0, posy: 80.0, width: 100.0, height: 20.0 }));
+}
+
+#[test]
+fn multi_column_min_column_width() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(320.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_min_column_width(node, Units::Pixels(100.0));
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let children: Vec<_> = (0..3).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 110.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 220.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_auto_height() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_row_between(node, Units::Pixels(10.0));
+
+    let children: Vec<_> = (0..5).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 170.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 0.0, posy: 120.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[3]), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[4]), Some(&Rect { posx: 100.0, posy: 60.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_padding() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(220.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_padding_left(node, Units::Pixels(10.0));
+    world.set_padding_right(node, Units::Pixels(10.0));
+    world.set_padding_top(node, Units::Pixels(5.0));
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+
+    let children: Vec<_> = (0..2).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 10.0, posy: 5.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 110.0, posy: 5.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_self_directed() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(210.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let badge = world.add(Some(node));
+    world.set_width(badge, Units::Pixels(40.0));
+    world.set_height(badge, Units::Pixels(40.0));
+    world.set_left(badge, Units::Pixels(500.0));
+    world.set_top(badge, Units::Pixels(500.0));
+    world.set_position_type(badge, PositionType::SelfDirected);
+
+    let children: Vec<_> = (0..4).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 500.0, posy: 500.0, width: 40.0, height: 40.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 0.0, posy: 50.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 110.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[3]), Some(&Rect { posx: 110.0, posy: 50.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.content_extent(node), Some((540.0, 540.0)));
+}
+
+#[test]
+fn multi_column_auto_width() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_min_column_width(node, Units::Pixels(50.0));
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let mut children = Vec::new();
+    for width in [80.0, 60.0, 40.0] {
+        let child = world.add(Some(node));
+        world.set_width(child, Units::Pixels(width));
+        world.set_height(child, Units::Pixels(50.0));
+        children.push(child);
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 170.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 0.0, posy: 50.0, width: 60.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 90.0, posy: 0.0, width: 40.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_auto_width_min_column_width() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 3);
+    world.set_min_column_width(node, Units::Pixels(50.0));
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let children: Vec<_> = (0..3).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 170.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 60.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 120.0, posy: 0.0, width: 50.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_auto_height_percentage_child() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(210.0));
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Percentage(50.0));
+
+    let leaf = world.add(Some(child));
+    world.set_width(leaf, Units::Pixels(40.0));
+    world.set_height(leaf, Units::Pixels(40.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 210.0, height: 40.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 40.0 }));
+}
+
+#[test]
+fn multi_column_self_directed_contributes_to_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_col_between(node, Units::Pixels(10.0));
+
+    let children: Vec<_> = (0..2).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(50.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    let badge = world.add(Some(node));
+    world.set_width(badge, Units::Pixels(40.0));
+    world.set_height(badge, Units::Pixels(40.0));
+    world.set_left(badge, Units::Pixels(150.0));
+    world.set_top(badge, Units::Pixels(60.0));
+    world.set_position_type(badge, PositionType::SelfDirected);
+    world.set_contributes_to_size(badge, true);
+
+    let ignored = world.add(Some(node));
+    world.set_width(ignored, Units::Pixels(40.0));
+    world.set_height(ignored, Units::Pixels(40.0));
+    world.set_left(ignored, Units::Pixels(300.0));
+    world.set_top(ignored, Units::Pixels(300.0));
+    world.set_position_type(ignored, PositionType::SelfDirected);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 190.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 60.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 150.0, posy: 60.0, width: 40.0, height: 40.0 }));
+}
+
+#[test]
+fn multi_column_content_size_first_child() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(210.0));
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_col_between(node, Units::Pixels(10.0));
+    world.set_content_size(node, |_, width, _| (width.unwrap(), 50.0));
+    world.set_content_size_mode(node, ContentSizeMode::FirstChild);
+
+    let children: Vec<_> = (0..3).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Stretch(1.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 210.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 50.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 110.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 110.0, posy: 50.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn multi_column_content_size_max() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::MultiColumn);
+    world.set_column_count(node, 2);
+    world.set_col_between(node, Units::Pixels(10.0));
+    world.set_content_size(node, |_, _, _| (300.0, 120.0));
+    world.set_content_size_mode(node, ContentSizeMode::Max);
+
+    let children: Vec<_> = (0..2).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(50.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 120.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 155.0, posy: 0.0, width: 50.0, height: 50.0 }));
+}
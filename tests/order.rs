@@ -0,0 +1,111 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn order_column() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let sidebar = world.add(Some(root));
+    world.set_width(sidebar, Units::Pixels(100.0));
+    world.set_height(sidebar, Units::Pixels(50.0));
+    world.set_order(sidebar, 1);
+
+    let content = world.add(Some(root));
+    world.set_width(content, Units::Pixels(100.0));
+    world.set_height(content, Units::Pixels(150.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(content), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(sidebar), Some(&Rect { posx: 0.0, posy: 150.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn order_equal_keeps_tree_order() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(100.0));
+    world.set_height(node3, Units::Pixels(50.0));
+    world.set_order(node3, -1);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 200.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn order_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Auto);
+    world.set_child_top(node, Units::Pixels(10.0));
+    world.set_child_bottom(node, Units::Pixels(20.0));
+    world.set_row_between(node, Units::Pixels(5.0));
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Pixels(100.0));
+    world.set_height(child1, Units::Pixels(50.0));
+    world.set_order(child1, 1);
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Pixels(100.0));
+    world.set_height(child2, Units::Pixels(30.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 115.0 }));
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 0.0, posy: 10.0, width: 100.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(child1), Some(&Rect { posx: 0.0, posy: 45.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn order_dock() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::Dock);
+
+    let fill = world.add(Some(node));
+    world.set_order(fill, 1);
+
+    let left = world.add(Some(node));
+    world.set_width(left, Units::Pixels(50.0));
+    world.set_dock(left, Dock::Left);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(left), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 200.0 }));
+    assert_eq!(world.cache.bounds(fill), Some(&Rect { posx: 50.0, posy: 0.0, width: 150.0, height: 200.0 }));
+}
@@ -0,0 +1,275 @@
+use std::{cell::Cell, rc::Rc};
+
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn overflow_visible() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(250.0));
+    world.set_height(root, Units::Pixels(50.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_overflow_priority(node1, 0);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+    world.set_overflow_priority(node2, 0);
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(100.0));
+    world.set_height(node3, Units::Pixels(50.0));
+    world.set_overflow_priority(node3, 0);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 200.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert!(!world.cache.overflowed(node3));
+}
+
+#[test]
+fn overflow_collapse_lowest_priority() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(250.0));
+    world.set_height(root, Units::Pixels(50.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_overflow(root, Overflow::Collapse);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_overflow_priority(node1, 2);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+    world.set_overflow_priority(node2, 1);
+
+    let child = world.add(Some(node2));
+    world.set_width(child, Units::Pixels(50.0));
+    world.set_height(child, Units::Pixels(50.0));
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(100.0));
+    world.set_height(node3, Units::Pixels(50.0));
+    world.set_overflow_priority(node3, 3);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 50.0 }));
+
+    assert!(!world.cache.overflowed(node1));
+    assert!(world.cache.overflowed(node2));
+    assert!(!world.cache.overflowed(node3));
+}
+
+#[test]
+fn overflow_collapse_equal_priority_from_last() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(250.0));
+    world.set_height(root, Units::Pixels(50.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_overflow(root, Overflow::Collapse);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_overflow_priority(node1, 0);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+    world.set_overflow_priority(node2, 0);
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(100.0));
+    world.set_height(node3, Units::Pixels(50.0));
+    world.set_overflow_priority(node3, 0);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert!(world.cache.overflowed(node3));
+}
+
+#[test]
+fn overflow_collapse_without_priority() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(250.0));
+    world.set_height(root, Units::Pixels(50.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_overflow(root, Overflow::Collapse);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_overflow_priority(node1, 0);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+    world.set_overflow_priority(node2, 0);
+
+    let menu = world.add(Some(root));
+    world.set_width(menu, Units::Pixels(100.0));
+    world.set_height(menu, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert!(world.cache.overflowed(node2));
+    assert_eq!(world.cache.bounds(menu), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert!(!world.cache.overflowed(menu));
+}
+
+#[test]
+fn overflow_collapse_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(300.0));
+    world.set_height(root, Units::Pixels(50.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_overflow(root, Overflow::Collapse);
+    world.set_child_left(root, Units::Pixels(10.0));
+    world.set_child_right(root, Units::Pixels(10.0));
+    world.set_col_between(root, Units::Pixels(10.0));
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_overflow_priority(node1, 0);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+    world.set_overflow_priority(node2, 0);
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(70.0));
+    world.set_height(node3, Units::Pixels(50.0));
+    world.set_overflow_priority(node3, 0);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 10.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 120.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert!(world.cache.overflowed(node3));
+}
+
+#[test]
+fn overflow_collapse_stretch_minimum() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(250.0));
+    world.set_height(root, Units::Pixels(50.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_overflow(root, Overflow::Collapse);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Stretch(1.0));
+    world.set_min_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+    world.set_overflow_priority(node2, 0);
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(100.0));
+    world.set_height(node3, Units::Pixels(50.0));
+    world.set_overflow_priority(node3, 1);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 150.0, height: 50.0 }));
+    assert!(world.cache.overflowed(node2));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 150.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn overflow_collapse_auto_max_width() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_max_width(node, Units::Pixels(250.0));
+    world.set_height(node, Units::Pixels(50.0));
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_overflow(node, Overflow::Collapse);
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Pixels(100.0));
+    world.set_height(child1, Units::Pixels(50.0));
+    world.set_overflow_priority(child1, 0);
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Pixels(100.0));
+    world.set_height(child2, Units::Pixels(50.0));
+    world.set_overflow_priority(child2, 0);
+
+    let child3 = world.add(Some(node));
+    world.set_width(child3, Units::Pixels(100.0));
+    world.set_height(child3, Units::Pixels(50.0));
+    world.set_overflow_priority(child3, 0);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 50.0 }));
+    assert!(world.cache.overflowed(child3));
+}
+
+#[test]
+fn overflow_collapse_nested_measured_once() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let mut parent = root;
+    for _ in 0..12 {
+        let node = world.add(Some(parent));
+        world.set_width(node, Units::Auto);
+        world.set_height(node, Units::Auto);
+        world.set_overflow(node, Overflow::Collapse);
+        parent = node;
+    }
+
+    let count = Rc::new(Cell::new(0));
+    let counter = count.clone();
+    let leaf = world.add(Some(parent));
+    world.set_width(leaf, Units::Auto);
+    world.set_height(leaf, Units::Auto);
+    world.set_content_size(leaf, move |_, _, _| {
+        counter.set(counter.get() + 1);
+        (50.0, 20.0)
+    });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(count.get(), 1);
+    assert_eq!(world.cache.bounds(parent), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 20.0 }));
+}
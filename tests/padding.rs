@@ -0,0 +1,158 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn padding_pixels_child_pixels_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_padding(root, Units::Pixels(20.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_left(node, Units::Pixels(10.0));
+    world.set_top(node, Units::Pixels(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 30.0, posy: 30.0, width: 100.0, height: 100.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 30.0, posy: 30.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn padding_with_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_padding(root, Units::Pixels(20.0));
+    world.set_child_space(root, Units::Pixels(10.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Stretch(1.0));
+    world.set_height(node, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 30.0, posy: 30.0, width: 540.0, height: 540.0 }));
+}
+
+#[test]
+fn padding_and_border() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_padding(root, Units::Pixels(20.0));
+    world.set_border(root, Units::Pixels(5.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Stretch(1.0));
+    world.set_height(node, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 25.0, posy: 25.0, width: 550.0, height: 550.0 }));
+}
+
+#[test]
+fn padding_self_directed_child() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_padding(root, Units::Pixels(20.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_position_type(node, PositionType::SelfDirected);
+    world.set_left(node, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 480.0, posy: 20.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn padding_uneven_different_layout_type() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(400.0));
+    world.set_height(node, Units::Pixels(300.0));
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_padding_left(node, Units::Pixels(10.0));
+    world.set_padding_right(node, Units::Pixels(20.0));
+    world.set_padding_top(node, Units::Pixels(30.0));
+    world.set_padding_bottom(node, Units::Pixels(40.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Stretch(1.0));
+    world.set_height(child, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 10.0, posy: 30.0, width: 370.0, height: 230.0 }));
+}
+
+#[test]
+fn padding_parent_auto() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_padding_left(node, Units::Pixels(10.0));
+    world.set_padding_right(node, Units::Pixels(20.0));
+    world.set_padding_top(node, Units::Pixels(30.0));
+    world.set_padding_bottom(node, Units::Pixels(40.0));
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Pixels(100.0));
+    world.set_height(child1, Units::Pixels(50.0));
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Pixels(80.0));
+    world.set_height(child2, Units::Pixels(50.0));
+    world.set_left(child2, Units::Pixels(5.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 130.0, height: 170.0 }));
+    assert_eq!(world.cache.bounds(child1), Some(&Rect { posx: 10.0, posy: 30.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 15.0, posy: 80.0, width: 80.0, height: 50.0 }));
+
+    world.set_layout_type(node, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 215.0, height: 120.0 }));
+    assert_eq!(world.cache.bounds(child1), Some(&Rect { posx: 10.0, posy: 30.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 115.0, posy: 30.0, width: 80.0, height: 50.0 }));
+
+    world.set_layout_type(root, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 215.0, height: 120.0 }));
+}
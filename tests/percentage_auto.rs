@@ -0,0 +1,184 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn percentage_size_parent_auto() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+
+    let node1 = world.add(Some(node));
+    world.set_width(node1, Units::Pixels(200.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    let node2 = world.add(Some(node));
+    world.set_width(node2, Units::Percentage(50.0));
+    world.set_height(node2, Units::Percentage(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 100.0, width: 100.0, height: 50.0 }));
+
+    world.set_layout_type(node, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 200.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn percentage_size_parent_auto_with_children() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+
+    let node1 = world.add(Some(node));
+    world.set_width(node1, Units::Pixels(200.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    let node2 = world.add(Some(node));
+    world.set_width(node2, Units::Percentage(50.0));
+    world.set_height(node2, Units::Pixels(50.0));
+
+    let node3 = world.add(Some(node2));
+    world.set_width(node3, Units::Pixels(300.0));
+    world.set_height(node3, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 100.0, width: 150.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 0.0, width: 300.0, height: 50.0 }));
+}
+
+#[test]
+fn percentage_size_parent_auto_width() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::Row);
+
+    let node1 = world.add(Some(node));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Percentage(50.0));
+
+    let node2 = world.add(Some(node));
+    world.set_width(node2, Units::Percentage(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 200.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn percentage_max_size_parent_auto() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+
+    let node1 = world.add(Some(node));
+    world.set_width(node1, Units::Percentage(100.0));
+    world.set_max_width(node1, Units::Percentage(50.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    let node2 = world.add(Some(node1));
+    world.set_width(node2, Units::Pixels(200.0));
+    world.set_height(node2, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn percentage_space_parent_auto() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+
+    let node1 = world.add(Some(node));
+    world.set_left(node1, Units::Percentage(10.0));
+    world.set_top(node1, Units::Percentage(20.0));
+    world.set_width(node1, Units::Pixels(200.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 20.0, posy: 20.0, width: 200.0, height: 100.0 }));
+
+    world.set_layout_type(node, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 20.0, posy: 20.0, width: 200.0, height: 100.0 }));
+}
+
+#[test]
+fn percentage_child_space_parent_auto() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_child_left(node, Units::Percentage(10.0));
+    world.set_child_top(node, Units::Percentage(20.0));
+    world.set_row_between(node, Units::Percentage(10.0));
+
+    let node1 = world.add(Some(node));
+    world.set_width(node1, Units::Pixels(200.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    let node2 = world.add(Some(node));
+    world.set_width(node2, Units::Pixels(200.0));
+    world.set_height(node2, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 200.0 }));
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 20.0, posy: 40.0, width: 200.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 20.0, posy: 160.0, width: 200.0, height: 100.0 }));
+}
@@ -0,0 +1,214 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn radial_full_circle() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::Radial);
+
+    let children: Vec<_> = (0..4).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(20.0));
+        world.set_height(*child, Units::Pixels(20.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 90.0, posy: -10.0, width: 20.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 190.0, posy: 90.0, width: 20.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 90.0, posy: 190.0, width: 20.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(children[3]), Some(&Rect { posx: -10.0, posy: 90.0, width: 20.0, height: 20.0 }));
+}
+
+#[test]
+fn radial_span() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::Radial);
+    world.set_start_angle(node, 270.0);
+    world.set_angular_span(node, 180.0);
+    world.set_radius(node, Units::Pixels(50.0));
+
+    let children: Vec<_> = (0..3).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(20.0));
+        world.set_height(*child, Units::Pixels(10.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 40.0, posy: 95.0, width: 20.0, height: 10.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 90.0, posy: 45.0, width: 20.0, height: 10.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 140.0, posy: 95.0, width: 20.0, height: 10.0 }));
+}
+
+#[test]
+fn radial_percentage_radius() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(300.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_padding_top(node, Units::Pixels(10.0));
+    world.set_padding_bottom(node, Units::Pixels(10.0));
+    world.set_layout_type(node, LayoutType::Radial);
+    world.set_start_angle(node, 90.0);
+    world.set_radius(node, Units::Percentage(50.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(20.0));
+    world.set_height(child, Units::Pixels(20.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 185.0, posy: 90.0, width: 20.0, height: 20.0 }));
+}
+
+#[test]
+fn radial_self_directed() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_layout_type(node, LayoutType::Radial);
+
+    let centre = world.add(Some(node));
+    world.set_position_type(centre, PositionType::SelfDirected);
+    world.set_left(centre, Units::Pixels(80.0));
+    world.set_top(centre, Units::Pixels(80.0));
+    world.set_width(centre, Units::Pixels(40.0));
+    world.set_height(centre, Units::Pixels(40.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(20.0));
+    world.set_height(child, Units::Pixels(20.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(centre), Some(&Rect { posx: 80.0, posy: 80.0, width: 40.0, height: 40.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 90.0, posy: -10.0, width: 20.0, height: 20.0 }));
+}
+
+#[test]
+fn radial_auto_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Radial);
+    world.set_radius(node, Units::Pixels(50.0));
+
+    let children: Vec<_> = (0..4).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(50.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 150.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 50.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 100.0, posy: 50.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 50.0, posy: 100.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[3]), Some(&Rect { posx: 0.0, posy: 50.0, width: 50.0, height: 50.0 }));
+}
+
+#[test]
+fn radial_auto_height() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(200.0));
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Radial);
+
+    let children: Vec<_> = (0..2).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(20.0));
+        world.set_height(*child, Units::Pixels(20.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 220.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 90.0, posy: 0.0, width: 20.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 90.0, posy: 200.0, width: 20.0, height: 20.0 }));
+}
+
+#[test]
+fn radial_auto_size_self_directed_contributes_to_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_layout_type(node, LayoutType::Radial);
+    world.set_radius(node, Units::Pixels(50.0));
+
+    let children: Vec<_> = (0..4).map(|_| world.add(Some(node))).collect();
+    for child in children.iter() {
+        world.set_width(*child, Units::Pixels(50.0));
+        world.set_height(*child, Units::Pixels(50.0));
+    }
+
+    let badge = world.add(Some(node));
+    world.set_width(badge, Units::Pixels(40.0));
+    world.set_height(badge, Units::Pixels(40.0));
+    world.set_left(badge, Units::Pixels(160.0));
+    world.set_top(badge, Units::Pixels(0.0));
+    world.set_position_type(badge, PositionType::SelfDirected);
+    world.set_contributes_to_size(badge, true);
+
+    let ignored = world.add(Some(node));
+    world.set_width(ignored, Units::Pixels(40.0));
+    world.set_height(ignored, Units::Pixels(40.0));
+    world.set_left(ignored, Units::Pixels(300.0));
+    world.set_top(ignored, Units::Pixels(300.0));
+    world.set_position_type(ignored, PositionType::SelfDirected);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 150.0 }));
+    assert_eq!(world.cache.bounds(children[0]), Some(&Rect { posx: 75.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[1]), Some(&Rect { posx: 125.0, posy: 50.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[2]), Some(&Rect { posx: 75.0, posy: 100.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(children[3]), Some(&Rect { posx: 25.0, posy: 50.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 160.0, posy: 0.0, width: 40.0, height: 40.0 }));
+}
@@ -0,0 +1,200 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn content_extent_fits() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.content_extent(root), Some((600.0, 600.0)));
+    assert_eq!(world.cache.content_extent(node), Some((100.0, 50.0)));
+}
+
+#[test]
+fn content_extent_overflow() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_padding_bottom(node, Units::Pixels(10.0));
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Pixels(150.0));
+    world.set_height(child1, Units::Pixels(60.0));
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Pixels(50.0));
+    world.set_height(child2, Units::Pixels(60.0));
+    world.set_bottom(child2, Units::Pixels(20.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.content_extent(node), Some((150.0, 150.0)));
+}
+
+#[test]
+fn content_extent_row() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_layout_type(node, LayoutType::Row);
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Pixels(80.0));
+    world.set_height(child1, Units::Pixels(50.0));
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Pixels(80.0));
+    world.set_height(child2, Units::Pixels(120.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.content_extent(node), Some((160.0, 120.0)));
+}
+
+#[test]
+fn scroll_offset() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_x(node, 10.0);
+    world.set_scroll_y(node, 30.0);
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Pixels(100.0));
+    world.set_height(child1, Units::Pixels(60.0));
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Pixels(100.0));
+    world.set_height(child2, Units::Pixels(60.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child1), Some(&Rect { posx: -10.0, posy: -30.0, width: 100.0, height: 60.0 }));
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: -10.0, posy: 30.0, width: 100.0, height: 60.0 }));
+    assert_eq!(world.cache.content_extent(node), Some((100.0, 120.0)));
+}
+
+#[test]
+fn scroll_offset_not_scrolling() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_scroll_y(node, 30.0);
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(100.0));
+    world.set_height(child, Units::Pixels(60.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 60.0 }));
+}
+
+#[test]
+fn scroll_stretch_minimum() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_overflow(node, Overflow::Scroll);
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Stretch(1.0));
+    world.set_height(child1, Units::Pixels(250.0));
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Stretch(1.0));
+    world.set_height(child2, Units::Stretch(1.0));
+    world.set_min_height(child2, Units::Pixels(20.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 250.0 }));
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 0.0, posy: 250.0, width: 100.0, height: 20.0 }));
+}
+
+#[test]
+fn scroll_stretch_visible_region() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_overflow(node, Overflow::Scroll);
+
+    let child1 = world.add(Some(node));
+    world.set_width(child1, Units::Stretch(1.0));
+    world.set_height(child1, Units::Pixels(50.0));
+
+    let child2 = world.add(Some(node));
+    world.set_width(child2, Units::Stretch(1.0));
+    world.set_height(child2, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 0.0, posy: 50.0, width: 100.0, height: 150.0 }));
+    assert_eq!(world.cache.content_extent(node), Some((100.0, 200.0)));
+}
+
+#[test]
+fn scroll_unsized_child() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(200.0));
+    world.set_overflow(node, Overflow::Scroll);
+
+    let child = world.add(Some(node));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 200.0 }));
+}
@@ -330,3 +330,74 @@ fn auto_parent_pixels_child_percentage_self_directed_child() {
     assert_eq!(world.cache.bounds(child1), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
     assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 0.0, posy: 0.0, width: 25.0, height: 13.0 }));
 }
+
+#[test]
+fn self_directed_contributes_to_auto_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(100.0));
+    world.set_height(child, Units::Pixels(100.0));
+
+    let badge = world.add(Some(node));
+    world.set_position_type(badge, PositionType::SelfDirected);
+    world.set_left(badge, Units::Pixels(80.0));
+    world.set_top(badge, Units::Pixels(90.0));
+    world.set_width(badge, Units::Pixels(40.0));
+    world.set_height(badge, Units::Pixels(40.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+
+    world.set_contributes_to_size(badge, true);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 120.0, height: 130.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 80.0, posy: 90.0, width: 40.0, height: 40.0 }));
+
+    world.set_layout_type(node, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 120.0, height: 130.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 80.0, posy: 90.0, width: 40.0, height: 40.0 }));
+}
+
+#[test]
+fn self_directed_only_contributes_to_auto_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_padding(node, Units::Pixels(5.0));
+
+    let badge = world.add(Some(node));
+    world.set_position_type(badge, PositionType::SelfDirected);
+    world.set_contributes_to_size(badge, true);
+    world.set_left(badge, Units::Pixels(10.0));
+    world.set_top(badge, Units::Pixels(20.0));
+    world.set_width(badge, Units::Pixels(50.0));
+    world.set_height(badge, Units::Pixels(30.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 70.0, height: 60.0 }));
+    assert_eq!(world.cache.bounds(badge), Some(&Rect { posx: 15.0, posy: 25.0, width: 50.0, height: 30.0 }));
+}
@@ -0,0 +1,155 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn size_group_rows() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let mut rows = Vec::new();
+    for label_width in [50.0, 80.0, 30.0] {
+        let row = world.add(Some(root));
+        world.set_height(row, Units::Pixels(20.0));
+        world.set_layout_type(row, LayoutType::Row);
+
+        let label = world.add(Some(row));
+        world.set_width(label, Units::Auto);
+        world.set_content_size(label, move |_, _, _| (label_width, 20.0));
+        world.set_size_group(label, 1);
+
+        let value = world.add(Some(row));
+        world.set_width(value, Units::Stretch(1.0));
+
+        rows.push((label, value));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    for (label, value) in rows {
+        assert_eq!(world.cache.bounds(label), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 20.0 }));
+        assert_eq!(world.cache.bounds(value), Some(&Rect { posx: 80.0, posy: 0.0, width: 520.0, height: 20.0 }));
+    }
+}
+
+#[test]
+fn size_group_separate_groups() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Auto);
+    world.set_height(node1, Units::Auto);
+    world.set_content_size(node1, |_, _, _| (50.0, 30.0));
+    world.set_size_group(node1, 1);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Auto);
+    world.set_height(node2, Units::Auto);
+    world.set_content_size(node2, |_, _, _| (100.0, 20.0));
+    world.set_size_group(node2, 1);
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Auto);
+    world.set_height(node3, Units::Auto);
+    world.set_content_size(node3, |_, _, _| (70.0, 10.0));
+    world.set_size_group(node3, 2);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 30.0, width: 100.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 0.0, posy: 60.0, width: 70.0, height: 10.0 }));
+}
+
+#[test]
+fn size_group_fixed_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Auto);
+    world.set_height(node1, Units::Pixels(20.0));
+    world.set_content_size(node1, |_, _, _| (50.0, 20.0));
+    world.set_size_group(node1, 1);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(200.0));
+    world.set_height(node2, Units::Pixels(20.0));
+    world.set_size_group(node2, 1);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 20.0, width: 200.0, height: 20.0 }));
+}
+
+#[test]
+fn size_group_breakpoint() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let mut labels = Vec::new();
+    for label_width in [50.0, 80.0] {
+        let label = world.add(Some(root));
+        world.set_width(label, Units::Pixels(300.0));
+        world.set_height(label, Units::Pixels(20.0));
+        world.set_content_size(label, move |_, _, _| (label_width, 20.0));
+        world.set_size_group(label, 1);
+        world.set_breakpoint(label, |query| {
+            (query.parent_width < 800.0).then_some(Breakpoint { width: Some(Units::Auto), ..Default::default() })
+        });
+        labels.push(label);
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(labels[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(labels[1]), Some(&Rect { posx: 0.0, posy: 20.0, width: 80.0, height: 20.0 }));
+}
+
+#[test]
+fn size_group_collapsed_parent() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+    world.set_content_size(node, |_, _, _| (50.0, 20.0));
+    world.set_size_group(node, 1);
+
+    let parent = world.add(Some(root));
+    world.set_width(parent, Units::Auto);
+    world.set_height(parent, Units::Auto);
+
+    let hidden = world.add(Some(parent));
+    world.set_width(hidden, Units::Auto);
+    world.set_height(hidden, Units::Auto);
+    world.set_content_size(hidden, |_, _, _| (200.0, 20.0));
+    world.set_size_group(hidden, 1);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 20.0 }));
+
+    world.set_visibility(parent, Visibility::Collapsed);
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(hidden), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}
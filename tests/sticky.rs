@@ -0,0 +1,189 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn sticky_not_scrolling() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(100.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(20.0));
+    world.set_position_type(node2, PositionType::Sticky);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 100.0, width: 100.0, height: 20.0 }));
+}
+
+#[test]
+fn sticky_scrolled() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_y(node, 50.0);
+
+    let header = world.add(Some(node));
+    world.set_width(header, Units::Pixels(100.0));
+    world.set_height(header, Units::Pixels(20.0));
+    world.set_position_type(header, PositionType::Sticky);
+
+    let item = world.add(Some(node));
+    world.set_width(item, Units::Pixels(100.0));
+    world.set_height(item, Units::Pixels(150.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(header), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(item), Some(&Rect { posx: 0.0, posy: -30.0, width: 100.0, height: 150.0 }));
+}
+
+#[test]
+fn sticky_padding() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_padding_top(node, Units::Pixels(10.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_y(node, 30.0);
+
+    let header = world.add(Some(node));
+    world.set_width(header, Units::Pixels(100.0));
+    world.set_height(header, Units::Pixels(20.0));
+    world.set_position_type(header, PositionType::Sticky);
+
+    let item = world.add(Some(node));
+    world.set_width(item, Units::Pixels(100.0));
+    world.set_height(item, Units::Pixels(150.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(header), Some(&Rect { posx: 0.0, posy: 10.0, width: 100.0, height: 20.0 }));
+}
+
+#[test]
+fn sticky_within_parent() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_y(node, 110.0);
+
+    let mut sections = Vec::new();
+    for _ in 0..2 {
+        let section = world.add(Some(node));
+        world.set_width(section, Units::Pixels(100.0));
+        world.set_height(section, Units::Auto);
+
+        let header = world.add(Some(section));
+        world.set_width(header, Units::Pixels(100.0));
+        world.set_height(header, Units::Pixels(20.0));
+        world.set_position_type(header, PositionType::Sticky);
+
+        let item = world.add(Some(section));
+        world.set_width(item, Units::Pixels(100.0));
+        world.set_height(item, Units::Pixels(100.0));
+
+        sections.push((section, header, item));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    let (section1, header1, item1) = sections[0];
+    assert_eq!(world.cache.bounds(section1), Some(&Rect { posx: 0.0, posy: -110.0, width: 100.0, height: 120.0 }));
+    assert_eq!(world.cache.bounds(header1), Some(&Rect { posx: 0.0, posy: 100.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(item1), Some(&Rect { posx: 0.0, posy: 20.0, width: 100.0, height: 100.0 }));
+
+    let (section2, header2, _) = sections[1];
+    assert_eq!(world.cache.bounds(section2), Some(&Rect { posx: 0.0, posy: 10.0, width: 100.0, height: 120.0 }));
+    assert_eq!(world.cache.bounds(header2), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 20.0 }));
+}
+
+#[test]
+fn sticky_zero_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_y(node, 50.0);
+
+    let marker = world.add(Some(node));
+    world.set_width(marker, Units::Pixels(0.0));
+    world.set_height(marker, Units::Pixels(0.0));
+    world.set_position_type(marker, PositionType::Sticky);
+
+    let item = world.add(Some(node));
+    world.set_width(item, Units::Pixels(100.0));
+    world.set_height(item, Units::Pixels(150.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(marker), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}
+
+#[test]
+fn sticky_collapsed() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_padding_top(node, Units::Pixels(10.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_y(node, 50.0);
+
+    let item = world.add(Some(node));
+    world.set_width(item, Units::Pixels(100.0));
+    world.set_height(item, Units::Pixels(150.0));
+
+    let header = world.add(Some(node));
+    world.set_width(header, Units::Pixels(100.0));
+    world.set_height(header, Units::Pixels(20.0));
+    world.set_position_type(header, PositionType::Sticky);
+    world.set_visibility(header, Visibility::Collapsed);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(header), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+
+    world.set_visibility(header, Visibility::Visible);
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(header), Some(&Rect { posx: 0.0, posy: 110.0, width: 100.0, height: 20.0 }));
+}
@@ -0,0 +1,133 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn translate_pixels() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(50.0));
+    world.set_translate_x(node1, Units::Pixels(5.0));
+    world.set_translate_y(node1, Units::Pixels(10.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 5.0, posy: 10.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 50.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn translate_percentage() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(50.0));
+    world.set_left(node, Units::Pixels(300.0));
+    world.set_top(node, Units::Pixels(300.0));
+    world.set_translate_x(node, Units::Percentage(-50.0));
+    world.set_translate_y(node, Units::Percentage(-50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 250.0, posy: 275.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn translate_does_not_affect_parent() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Auto);
+    world.set_height(node, Units::Auto);
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(100.0));
+    world.set_height(child, Units::Pixels(50.0));
+    world.set_translate_y(child, Units::Pixels(20.0));
+
+    let grandchild = world.add(Some(child));
+    world.set_width(grandchild, Units::Pixels(10.0));
+    world.set_height(grandchild, Units::Pixels(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 20.0, width: 100.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(grandchild), Some(&Rect { posx: 0.0, posy: 0.0, width: 10.0, height: 10.0 }));
+    assert_eq!(world.cache.content_extent(node), Some((100.0, 50.0)));
+}
+
+#[test]
+fn translate_repeated_layout() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(50.0));
+    world.set_translate_x(node, Units::Pixels(-10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: -10.0, posy: 0.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn translate_zero_size() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(0.0));
+    world.set_height(node, Units::Pixels(0.0));
+    world.set_translate_x(node, Units::Pixels(5.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 5.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}
+
+#[test]
+fn translate_collapsed() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(50.0));
+    world.set_translate_x(node, Units::Pixels(5.0));
+    world.set_visibility(node, Visibility::Collapsed);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}
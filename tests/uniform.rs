@@ -0,0 +1,165 @@
+use std::{cell::Cell, rc::Rc};
+
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn uniform_children_main() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(40.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_uniform_children(root, UniformChildren::Main);
+
+    let mut tabs = Vec::new();
+    for tab_width in [50.0, 80.0, 60.0] {
+        let tab = world.add(Some(root));
+        world.set_width(tab, Units::Auto);
+        world.set_content_size(tab, move |_, _, _| (tab_width, 20.0));
+        tabs.push(tab);
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(tabs[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(tabs[1]), Some(&Rect { posx: 80.0, posy: 0.0, width: 80.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(tabs[2]), Some(&Rect { posx: 160.0, posy: 0.0, width: 80.0, height: 20.0 }));
+}
+
+#[test]
+fn uniform_children_max_constraint() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(40.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_uniform_children(root, UniformChildren::Main);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(50.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Pixels(50.0));
+    world.set_max_width(node3, Units::Pixels(70.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 40.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 100.0, posy: 0.0, width: 100.0, height: 40.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 200.0, posy: 0.0, width: 70.0, height: 40.0 }));
+}
+
+#[test]
+fn uniform_children_main_and_cross() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(400.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_uniform_children(root, UniformChildren::MainAndCross);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Auto);
+    world.set_height(node1, Units::Auto);
+    world.set_content_size(node1, |_, _, _| (50.0, 30.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Auto);
+    world.set_height(node2, Units::Auto);
+    world.set_content_size(node2, |_, _, _| (80.0, 20.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 80.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 80.0, posy: 0.0, width: 80.0, height: 30.0 }));
+}
+
+#[test]
+fn uniform_children_stretch() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(400.0));
+    world.set_height(root, Units::Pixels(40.0));
+    world.set_layout_type(root, LayoutType::Row);
+    world.set_uniform_children(root, UniformChildren::Main);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(50.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+
+    let node3 = world.add(Some(root));
+    world.set_width(node3, Units::Stretch(1.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 40.0 }));
+    assert_eq!(world.cache.bounds(node3), Some(&Rect { posx: 200.0, posy: 0.0, width: 200.0, height: 40.0 }));
+}
+
+#[test]
+fn uniform_children_auto_parent() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Auto);
+    world.set_uniform_children(node, UniformChildren::Main);
+
+    let child1 = world.add(Some(node));
+    world.set_height(child1, Units::Pixels(20.0));
+
+    let child2 = world.add(Some(node));
+    world.set_height(child2, Units::Pixels(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(child2), Some(&Rect { posx: 0.0, posy: 50.0, width: 100.0, height: 50.0 }));
+}
+
+#[test]
+fn uniform_children_nested_measured_once() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let mut parent = root;
+    for _ in 0..12 {
+        let node = world.add(Some(parent));
+        world.set_width(node, Units::Auto);
+        world.set_height(node, Units::Auto);
+        world.set_uniform_children(node, UniformChildren::Main);
+        parent = node;
+    }
+
+    let count = Rc::new(Cell::new(0));
+    let counter = count.clone();
+    let leaf = world.add(Some(parent));
+    world.set_width(leaf, Units::Auto);
+    world.set_height(leaf, Units::Auto);
+    world.set_content_size(leaf, move |_, _, _| {
+        counter.set(counter.get() + 1);
+        (50.0, 20.0)
+    });
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(count.get(), 1);
+    assert_eq!(world.cache.bounds(leaf), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 20.0 }));
+}
@@ -0,0 +1,115 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn viewport_size_nested() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(800.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let container = world.add(Some(root));
+    world.set_width(container, Units::Pixels(200.0));
+    world.set_height(container, Units::Pixels(200.0));
+
+    let node = world.add(Some(container));
+    world.set_width(node, Units::ViewportWidth(80.0));
+    world.set_height(node, Units::ViewportHeight(50.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 640.0, height: 300.0 }));
+
+    world.set_layout_type(container, LayoutType::Row);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 640.0, height: 300.0 }));
+}
+
+#[test]
+fn viewport_width_height_cross_axis() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(800.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::ViewportHeight(10.0));
+    world.set_height(node, Units::ViewportWidth(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 60.0, height: 80.0 }));
+}
+
+#[test]
+fn viewport_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(800.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let container = world.add(Some(root));
+    world.set_width(container, Units::Pixels(400.0));
+    world.set_height(container, Units::Pixels(400.0));
+
+    let node = world.add(Some(container));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_left(node, Units::ViewportWidth(10.0));
+    world.set_top(node, Units::ViewportHeight(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 80.0, posy: 60.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn viewport_child_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(800.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let container = world.add(Some(root));
+    world.set_width(container, Units::Pixels(400.0));
+    world.set_height(container, Units::Pixels(400.0));
+    world.set_child_left(container, Units::ViewportWidth(5.0));
+    world.set_child_top(container, Units::ViewportHeight(5.0));
+
+    let node = world.add(Some(container));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 40.0, posy: 30.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn viewport_size_constraints() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(800.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let container = world.add(Some(root));
+    world.set_width(container, Units::Pixels(400.0));
+    world.set_height(container, Units::Pixels(400.0));
+
+    let node = world.add(Some(container));
+    world.set_width(node, Units::Stretch(1.0));
+    world.set_max_width(node, Units::ViewportWidth(25.0));
+    world.set_height(node, Units::Pixels(10.0));
+    world.set_min_height(node, Units::ViewportHeight(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 200.0, height: 60.0 }));
+}
@@ -0,0 +1,147 @@
+use morphorm::*;
+use morphorm_ecs::*;
+
+#[test]
+fn virtual_list() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_virtual_list(node, VirtualList { count: 1000, item_extent: 20.0, overscan: 0 });
+
+    let items: Vec<_> = (0..1000).map(|_| world.add(Some(node))).collect();
+    for item in items.iter() {
+        world.set_width(*item, Units::Stretch(1.0));
+        world.set_height(*item, Units::Stretch(1.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(items[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(items[4]), Some(&Rect { posx: 0.0, posy: 80.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(items[5]), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.content_extent(node), Some((100.0, 20000.0)));
+}
+
+#[test]
+fn virtual_list_scrolled() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_scroll_y(node, 210.0);
+    world.set_virtual_list(node, VirtualList { count: 1000, item_extent: 20.0, overscan: 2 });
+
+    let items: Vec<_> = (0..1000).map(|_| world.add(Some(node))).collect();
+    for item in items.iter() {
+        world.set_width(*item, Units::Stretch(1.0));
+        world.set_height(*item, Units::Stretch(1.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(items[7]), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(items[8]), Some(&Rect { posx: 0.0, posy: -50.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(items[10]), Some(&Rect { posx: 0.0, posy: -10.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(items[17]), Some(&Rect { posx: 0.0, posy: 130.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.bounds(items[18]), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}
+
+#[test]
+fn virtual_list_row() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(50.0));
+    world.set_layout_type(node, LayoutType::Row);
+    world.set_virtual_list(node, VirtualList { count: 10, item_extent: 40.0, overscan: 0 });
+
+    let items: Vec<_> = (0..10).map(|_| world.add(Some(node))).collect();
+    for item in items.iter() {
+        world.set_width(*item, Units::Pixels(30.0));
+        world.set_height(*item, Units::Pixels(30.0));
+        world.set_top(*item, Units::Pixels(10.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(items[2]), Some(&Rect { posx: 80.0, posy: 10.0, width: 30.0, height: 30.0 }));
+    assert_eq!(world.cache.bounds(items[3]), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.content_extent(node), Some((400.0, 50.0)));
+}
+
+#[test]
+fn virtual_list_auto_height() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Auto);
+    world.set_padding_top(node, Units::Pixels(10.0));
+    world.set_virtual_list(node, VirtualList { count: 5, item_extent: 20.0, overscan: 0 });
+
+    let items: Vec<_> = (0..5).map(|_| world.add(Some(node))).collect();
+    for item in items.iter() {
+        world.set_width(*item, Units::Stretch(1.0));
+        world.set_height(*item, Units::Stretch(1.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 110.0 }));
+    assert_eq!(world.cache.bounds(items[4]), Some(&Rect { posx: 0.0, posy: 90.0, width: 100.0, height: 20.0 }));
+}
+
+#[test]
+fn virtual_list_scroll_clears_items() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_overflow(node, Overflow::Scroll);
+    world.set_virtual_list(node, VirtualList { count: 1000, item_extent: 20.0, overscan: 0 });
+
+    let items: Vec<_> = (0..1000).map(|_| world.add(Some(node))).collect();
+    for item in items.iter() {
+        world.set_width(*item, Units::Stretch(1.0));
+        world.set_height(*item, Units::Stretch(1.0));
+    }
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(items[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.visible_range(node), Some(0..5));
+
+    world.set_scroll_y(node, 500.0);
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(items[0]), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(items[4]), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(items[25]), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 20.0 }));
+    assert_eq!(world.cache.visible_range(node), Some(25..30));
+}
@@ -12,7 +12,7 @@ fn visibility() {
     let node = world.add(Some(root));
     world.set_width(node, Units::Pixels(100.0));
     world.set_height(node, Units::Pixels(100.0));
-    world.set_visibility(node, false);
+    world.set_visibility(node, Visibility::Collapsed);
 
     let child = world.add(Some(node));
     world.set_width(child, Units::Pixels(100.0));
@@ -23,3 +23,136 @@ fn visibility() {
     assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
     assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
 }
+
+#[test]
+fn visibility_hidden_occupies_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(100.0));
+    world.set_visibility(node1, Visibility::Hidden);
+
+    let child = world.add(Some(node1));
+    world.set_width(child, Units::Pixels(50.0));
+    world.set_height(child, Units::Pixels(50.0));
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Pixels(100.0));
+    world.set_height(node2, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 100.0, width: 100.0, height: 100.0 }));
+}
+
+#[test]
+fn visibility_collapsed_takes_no_space() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+    world.set_layout_type(root, LayoutType::Row);
+
+    let node1 = world.add(Some(root));
+    world.set_width(node1, Units::Pixels(100.0));
+    world.set_height(node1, Units::Pixels(100.0));
+    world.set_visibility(node1, Visibility::Collapsed);
+
+    let node2 = world.add(Some(root));
+    world.set_width(node2, Units::Stretch(1.0));
+    world.set_height(node2, Units::Pixels(100.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 0.0, posy: 0.0, width: 600.0, height: 100.0 }));
+
+    world.set_visibility(node1, Visibility::Hidden);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node1), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(node2), Some(&Rect { posx: 100.0, posy: 0.0, width: 500.0, height: 100.0 }));
+}
+
+#[test]
+fn visibility_collapsed_clears_previous_bounds() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_left(node, Units::Pixels(50.0));
+    world.set_top(node, Units::Pixels(50.0));
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(50.0));
+    world.set_height(child, Units::Pixels(50.0));
+
+    let grandchild = world.add(Some(child));
+    world.set_width(grandchild, Units::Pixels(20.0));
+    world.set_height(grandchild, Units::Pixels(20.0));
+    world.set_left(grandchild, Units::Pixels(10.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 50.0, posy: 50.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 50.0, height: 50.0 }));
+    assert_eq!(world.cache.bounds(grandchild), Some(&Rect { posx: 10.0, posy: 0.0, width: 20.0, height: 20.0 }));
+
+    world.set_visibility(node, Visibility::Collapsed);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+    assert_eq!(world.cache.bounds(grandchild), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+
+    world.set_visibility(node, Visibility::Visible);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 50.0, posy: 50.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(grandchild), Some(&Rect { posx: 10.0, posy: 0.0, width: 20.0, height: 20.0 }));
+}
+
+#[test]
+fn visibility_collapsed_descendant_of_hidden() {
+    let mut world = World::default();
+
+    let root = world.add(None);
+    world.set_width(root, Units::Pixels(600.0));
+    world.set_height(root, Units::Pixels(600.0));
+
+    let node = world.add(Some(root));
+    world.set_width(node, Units::Pixels(100.0));
+    world.set_height(node, Units::Pixels(100.0));
+    world.set_visibility(node, Visibility::Hidden);
+
+    let child = world.add(Some(node));
+    world.set_width(child, Units::Pixels(50.0));
+    world.set_height(child, Units::Pixels(50.0));
+    world.set_top(child, Units::Pixels(25.0));
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 25.0, width: 50.0, height: 50.0 }));
+
+    world.set_visibility(child, Visibility::Collapsed);
+
+    root.layout(&mut world.cache, &world.tree, &world.store, &mut ());
+
+    assert_eq!(world.cache.bounds(node), Some(&Rect { posx: 0.0, posy: 0.0, width: 100.0, height: 100.0 }));
+    assert_eq!(world.cache.bounds(child), Some(&Rect { posx: 0.0, posy: 0.0, width: 0.0, height: 0.0 }));
+}